@@ -0,0 +1,90 @@
+//! Compares the byte level generators against the `split`/`parse` versions they replaced.
+
+use std::collections::HashMap;
+use std::hint::black_box;
+
+use cargo_aoc::synth;
+use cargo_aoc::y2024::day3::Instruction;
+use cargo_aoc::y2024::{day2, day3, day5};
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use regex::Regex;
+
+const SEED: u64 = 2024;
+
+const SCALES: [usize; 2] = [1, 10];
+
+mod legacy {
+    use super::*;
+
+    pub fn day2(input: &str) -> Vec<Vec<i32>> {
+        input
+            .lines()
+            .map(|x| x.split(' ').map(|t| t.parse().unwrap()).collect())
+            .collect()
+    }
+
+    /// Copying the input and finding the instructions with the regular expressions the parts used
+    pub fn day3(input: &str) -> Vec<Instruction> {
+        let input = String::from(input);
+        let re = Regex::new(r"mul\(([\d]{1,3}),([\d]{1,3})\)|don't\(\)|do\(\)").unwrap();
+        re.captures_iter(&input)
+            .map(|cap| match &cap[0] {
+                "do()" => Instruction::Do,
+                "don't()" => Instruction::Dont,
+                _ => Instruction::Mul(cap[1].parse().unwrap(), cap[2].parse().unwrap()),
+            })
+            .collect()
+    }
+
+    pub fn day5(input: &str) -> (HashMap<u32, Vec<u32>>, Vec<Vec<u32>>) {
+        let mut section_1 = vec![];
+        let mut section_2 = vec![];
+        let mut working = &mut section_1;
+        for line in input.lines() {
+            if line.is_empty() {
+                working = &mut section_2;
+            } else {
+                working.push(line);
+            }
+        }
+
+        let mut order_map: HashMap<u32, Vec<u32>> = HashMap::new();
+        for order in section_1 {
+            let (a, b) = order.split_once("|").unwrap();
+            order_map
+                .entry(a.parse().unwrap())
+                .or_default()
+                .push(b.parse().unwrap());
+        }
+        let pages = section_2
+            .iter()
+            .map(|line| line.split(",").map(|s| s.parse::<u32>().unwrap()).collect())
+            .collect();
+        (order_map, pages)
+    }
+}
+
+fn bench_parser<T, U>(c: &mut Criterion, day: u8, legacy: fn(&str) -> T, current: fn(&str) -> U) {
+    let mut group = c.benchmark_group(format!("parse_day{}", day));
+    for scale in SCALES {
+        let input = synth::generate(2024, day, SEED, scale).expect("No synthetic input generator");
+        let label = format!("synth_x{}", scale);
+        group.throughput(Throughput::Bytes(input.len() as u64));
+        group.bench_with_input(BenchmarkId::new("legacy", &label), &input, |b, input| {
+            b.iter(|| legacy(black_box(input)))
+        });
+        group.bench_with_input(BenchmarkId::new("bytes", &label), &input, |b, input| {
+            b.iter(|| current(black_box(input)))
+        });
+    }
+    group.finish();
+}
+
+fn benches(c: &mut Criterion) {
+    bench_parser(c, 2, legacy::day2, day2::input_generator);
+    bench_parser(c, 3, legacy::day3, day3::input_generator);
+    bench_parser(c, 5, legacy::day5, day5::input_generator_day5);
+}
+
+criterion_group!(parsing, benches);
+criterion_main!(parsing);
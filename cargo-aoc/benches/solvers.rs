@@ -0,0 +1,152 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::hint::black_box;
+use std::path::{Path, PathBuf};
+
+use cargo_aoc::fixtures;
+use cargo_aoc::solver::Puzzle;
+use cargo_aoc::synth;
+use criterion::{BenchmarkId, Criterion, Throughput};
+use serde_json::{json, Value};
+
+/// Seed for the synthetic inputs, fixed so every run benchmarks exactly the same inputs
+const SEED: u64 = 2024;
+
+/// Synthetic input sizes relative to a real puzzle input
+const SCALES: [usize; 2] = [1, 10];
+
+/// The first example listed for each day in the expected answers registry
+fn examples() -> BTreeMap<(u16, u8), String> {
+    let mut examples = BTreeMap::new();
+    let entries = fixtures::expected_answers(&fixtures::root());
+    for entry in entries.expect("Failed to read expected answers") {
+        examples.entry((entry.year, entry.day)).or_insert(entry.fixture);
+    }
+    examples
+}
+
+/// The puzzle example, when there is one, followed by synthetic inputs of increasing size for the
+/// days that have a generator
+fn inputs(year: u16, day: u8, example: Option<&str>) -> Vec<(String, String)> {
+    let mut inputs = Vec::new();
+    if let Some(example) = example {
+        inputs.push(("example".to_string(), fixtures::load(year, example)));
+    }
+    for scale in SCALES {
+        if let Some(input) = synth::generate(year, day, SEED, scale) {
+            inputs.push((format!("synth_x{}", scale), input));
+        }
+    }
+    inputs
+}
+
+fn bench_puzzle(c: &mut Criterion, puzzle: &dyn Puzzle, example: Option<&str>) {
+    let (year, day) = (puzzle.year(), puzzle.day());
+    let inputs = inputs(year, day, example);
+    if inputs.is_empty() {
+        eprintln!("Skipping day {} of {}, it has no example or synthetic input", day, year);
+        return;
+    }
+
+    let mut group = c.benchmark_group(format!("{}_day{}", year, day));
+    for (label, input) in inputs {
+        group.throughput(Throughput::Bytes(input.len() as u64));
+        group.bench_with_input(BenchmarkId::new("generator", &label), &input, |b, input| {
+            b.iter(|| puzzle.parse_input(black_box(input)))
+        });
+        let parsed = puzzle.parse_input(&input);
+        for part in [1, 2] {
+            if puzzle.solve(parsed.as_ref(), part).is_none() {
+                continue;
+            }
+            let id = BenchmarkId::new(format!("part{}", part), &label);
+            group.bench_with_input(id, &parsed, |b, parsed| {
+                b.iter(|| puzzle.solve(black_box(parsed.as_ref()), part))
+            });
+        }
+    }
+    group.finish();
+}
+
+/// Every registered day, so new days are benchmarked as soon as they are registered
+fn benches(c: &mut Criterion) {
+    let examples = examples();
+    for puzzle in cargo_aoc::registry().iter() {
+        let example = examples.get(&(puzzle.year(), puzzle.day()));
+        bench_puzzle(c, puzzle, example.map(String::as_str));
+    }
+}
+
+/// Criterion writes its reports to `$CRITERION_HOME`, or `criterion/` inside the cargo target
+/// directory, which we find from the location of this benchmark executable
+fn criterion_home() -> PathBuf {
+    if let Some(home) = std::env::var_os("CRITERION_HOME") {
+        return PathBuf::from(home);
+    }
+    if let Some(target) = std::env::var_os("CARGO_TARGET_DIR") {
+        return PathBuf::from(target).join("criterion");
+    }
+    let exe = std::env::current_exe().expect("Cannot locate benchmark executable");
+    // target/<profile>/deps/<bench executable>
+    exe.ancestors()
+        .nth(3)
+        .expect("Benchmark executable is not inside a target directory")
+        .join("criterion")
+}
+
+fn read_json(path: &Path) -> Option<Value> {
+    serde_json::from_str(&fs::read_to_string(path).ok()?).ok()
+}
+
+fn collect_estimates(dir: &Path, summary: &mut Vec<Value>) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if !path.is_dir() || path.file_name().is_some_and(|name| name == "report") {
+            continue;
+        }
+        let benchmark = read_json(&path.join("new/benchmark.json"));
+        let estimates = read_json(&path.join("new/estimates.json"));
+        if let (Some(benchmark), Some(estimates)) = (benchmark, estimates) {
+            summary.push(json!({
+                "id": benchmark["full_id"],
+                "group": benchmark["group_id"],
+                "function": benchmark["function_id"],
+                "input": benchmark["value_str"],
+                "throughput": benchmark["throughput"],
+                "mean_ns": estimates["mean"]["point_estimate"],
+                "median_ns": estimates["median"]["point_estimate"],
+                "std_dev_ns": estimates["std_dev"]["point_estimate"],
+            }));
+        } else {
+            collect_estimates(&path, summary);
+        }
+    }
+}
+
+/// Gathers the latest estimate of every benchmark into a single `summary.json` next to the
+/// HTML reports, so runs on different commits can be compared by a script
+fn write_summary() {
+    let home = criterion_home();
+    let mut summary = Vec::new();
+    collect_estimates(&home, &mut summary);
+    summary.sort_by(|a, b| a["id"].as_str().cmp(&b["id"].as_str()));
+
+    let path = home.join("summary.json");
+    let contents = serde_json::to_string_pretty(&json!({ "benchmarks": summary }))
+        .expect("Failed to serialise benchmark summary");
+    if let Err(e) = fs::create_dir_all(&home).and_then(|_| fs::write(&path, contents)) {
+        eprintln!("Failed to write benchmark summary to {:?}: {}", path, e);
+    } else {
+        println!("Benchmark summary written to {:?}", path);
+    }
+}
+
+fn main() {
+    let mut criterion = Criterion::default().configure_from_args();
+    benches(&mut criterion);
+    criterion.final_summary();
+    write_summary();
+}
@@ -0,0 +1,193 @@
+//! A single answer type for every part of every day, so solutions can return whichever integer
+//! type is natural for the puzzle, or text, and still be displayed and compared the same way.
+
+use std::fmt::{Display, Formatter};
+
+use ndarray::Array2;
+
+#[derive(Debug, Clone)]
+pub enum Answer {
+    I64(i64),
+    U64(u64),
+    I128(i128),
+    U128(u128),
+    Text(String),
+    /// Multi line answer, such as letters drawn on a grid, one row per line
+    Grid(String),
+    /// Placeholder returned by a part that has not been solved yet, never a real answer
+    Unsolved,
+}
+
+impl Answer {
+    /// Builds a grid answer from a board, one line per row
+    pub fn grid(board: &Array2<char>) -> Self {
+        let rows = board
+            .rows()
+            .into_iter()
+            .map(|row| row.iter().collect::<String>())
+            .collect::<Vec<String>>();
+        Answer::Grid(rows.join("\n"))
+    }
+
+    pub fn is_unsolved(&self) -> bool {
+        matches!(self, Answer::Unsolved)
+    }
+
+    /// Compares against an expected answer as written by a person, ignoring surrounding
+    /// whitespace and, for grids, trailing whitespace on each line
+    pub fn matches(&self, expected: &str) -> bool {
+        match self {
+            Answer::Grid(grid) => normalise_grid(grid) == normalise_grid(expected),
+            Answer::Unsolved => false,
+            _ => self.to_string().as_str() == expected.trim(),
+        }
+    }
+
+    /// Integer answers widened so that equal values of different types compare equal
+    fn as_integer(&self) -> Option<Integer> {
+        match *self {
+            Answer::I64(v) => Some(Integer::from_signed(v as i128)),
+            Answer::U64(v) => Some(Integer::NonNegative(v as u128)),
+            Answer::I128(v) => Some(Integer::from_signed(v)),
+            Answer::U128(v) => Some(Integer::NonNegative(v)),
+            Answer::Text(_) | Answer::Grid(_) | Answer::Unsolved => None,
+        }
+    }
+}
+
+#[derive(PartialEq)]
+enum Integer {
+    Negative(i128),
+    NonNegative(u128),
+}
+
+impl Integer {
+    fn from_signed(v: i128) -> Self {
+        if v < 0 {
+            Integer::Negative(v)
+        } else {
+            Integer::NonNegative(v as u128)
+        }
+    }
+}
+
+fn normalise_grid(grid: &str) -> Vec<&str> {
+    grid.trim_matches('\n')
+        .lines()
+        .map(str::trim_end)
+        .collect()
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::I64(v) => write!(f, "{}", v),
+            Answer::U64(v) => write!(f, "{}", v),
+            Answer::I128(v) => write!(f, "{}", v),
+            Answer::U128(v) => write!(f, "{}", v),
+            Answer::Text(text) | Answer::Grid(text) => write!(f, "{}", text),
+            Answer::Unsolved => write!(f, "unsolved"),
+        }
+    }
+}
+
+impl PartialEq for Answer {
+    fn eq(&self, other: &Self) -> bool {
+        match (self.as_integer(), other.as_integer()) {
+            (Some(a), Some(b)) => a == b,
+            // Unsolved only ever equals itself, never text that happens to read the same
+            _ if self.is_unsolved() || other.is_unsolved() => {
+                self.is_unsolved() && other.is_unsolved()
+            }
+            _ => normalise_grid(&self.to_string()) == normalise_grid(&other.to_string()),
+        }
+    }
+}
+
+impl PartialEq<str> for Answer {
+    fn eq(&self, other: &str) -> bool {
+        self.matches(other)
+    }
+}
+
+impl PartialEq<&str> for Answer {
+    fn eq(&self, other: &&str) -> bool {
+        self.matches(other)
+    }
+}
+
+macro_rules! impl_from_integer {
+    ($variant:ident, $wide:ty, $($t:ty),+) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(value: $t) -> Self {
+                    Answer::$variant(value as $wide)
+                }
+            }
+
+            impl PartialEq<$t> for Answer {
+                fn eq(&self, other: &$t) -> bool {
+                    PartialEq::eq(self, &Answer::from(*other))
+                }
+            }
+        )+
+    };
+}
+
+impl_from_integer!(I64, i64, i8, i16, i32, i64, isize);
+impl_from_integer!(U64, u64, u8, u16, u32, u64, usize);
+impl_from_integer!(I128, i128, i128);
+impl_from_integer!(U128, u128, u128);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_integers_compare_across_types() {
+        assert_eq!(Answer::from(42u32), Answer::from(42i128));
+        assert_eq!(Answer::from(42usize), Answer::U128(42));
+        assert_ne!(Answer::from(-1i32), Answer::from(u64::MAX));
+        assert_ne!(Answer::from(-1i64), Answer::from(1u8));
+        assert_eq!(Answer::from(7u64), 7);
+        assert_eq!(Answer::from(u128::MAX), u128::MAX);
+    }
+
+    #[test]
+    fn test_matches_expected_text() {
+        assert!(Answer::from(1928u64).matches("1928\n"));
+        assert!(!Answer::from(1928u64).matches("1929"));
+        assert_eq!(Answer::from(-3i64), "-3");
+        assert_eq!(Answer::from("ABC"), " ABC ");
+        assert_eq!(Answer::from(12u32), Answer::from("12"));
+    }
+
+    #[test]
+    fn test_grid() {
+        let board = Array2::from_shape_vec((2, 3), "#.##..".chars().collect()).unwrap();
+        let answer = Answer::grid(&board);
+
+        assert_eq!(answer.to_string(), "#.#\n#..");
+        assert!(answer.matches("\n#.#  \n#..\n"));
+        assert!(!answer.matches("#.#"));
+    }
+
+    #[test]
+    fn test_unsolved() {
+        assert_eq!(Answer::Unsolved, Answer::Unsolved);
+        assert_ne!(Answer::Unsolved, Answer::from("unsolved"));
+        assert!(!Answer::Unsolved.matches("unsolved"));
+    }
+}
@@ -0,0 +1,215 @@
+use std::fs;
+use std::io;
+use std::process::ExitCode;
+
+use cargo_aoc::y2024::day6::debugger::{Debugger, Outcome};
+use cargo_aoc::y2024::day6;
+use clap::Args;
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Paragraph};
+use ratatui::{DefaultTerminal, Frame};
+
+use crate::SiteArgs;
+
+const HELP: [(&str, &str); 8] = [
+    ("arrows", "move cursor"),
+    ("n / space", "next turn"),
+    ("p / backspace", "previous turn"),
+    ("home / end", "first / last turn"),
+    ("<number> g", "jump to turn"),
+    ("o", "toggle obstruction"),
+    ("v", "toggle visited cells"),
+    ("q / esc", "quit"),
+];
+
+#[derive(Args, Debug)]
+pub struct DebugArgs {
+    /// Puzzle input file, the cached 2024 day 6 input is used when omitted
+    #[arg(short, long)]
+    input: Option<String>,
+
+    #[command(flatten)]
+    site: SiteArgs,
+}
+
+struct App {
+    debugger: Debugger,
+    /// Digits typed ahead of a jump
+    pending: String,
+    message: Option<String>,
+}
+
+pub fn debug(args: &DebugArgs) -> ExitCode {
+    let input = match &args.input {
+        Some(path) => fs::read_to_string(path).map_err(|e| format!("Failed to read input {:?}: {}", path, e)),
+        None => args.site.input_cache().resolve(2024, 6).map_err(|e| e.to_string()),
+    };
+    let input = match input {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("{}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let (board, guard) = day6::input_generator_day6(&input);
+    let mut app = App {
+        debugger: Debugger::new(board, guard),
+        pending: String::new(),
+        message: None,
+    };
+
+    let mut terminal = ratatui::init();
+    let result = app.run(&mut terminal);
+    ratatui::restore();
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("Terminal error: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+impl App {
+    fn run(&mut self, terminal: &mut DefaultTerminal) -> io::Result<()> {
+        loop {
+            terminal.draw(|frame| self.draw(frame))?;
+            let Event::Key(key) = event::read()? else {
+                continue;
+            };
+            if key.kind != KeyEventKind::Press {
+                continue;
+            }
+
+            self.message = None;
+            let debugger = &mut self.debugger;
+            match key.code {
+                KeyCode::Char('q') => return Ok(()),
+                KeyCode::Esc if self.pending.is_empty() => return Ok(()),
+                KeyCode::Esc => self.pending.clear(),
+                KeyCode::Char(digit @ '0'..='9') => self.pending.push(digit),
+                KeyCode::Char('g') | KeyCode::Enter => {
+                    match self.pending.parse() {
+                        Ok(turn) => debugger.jump(turn),
+                        Err(_) => self.message = Some("Type a turn number before jumping".to_string()),
+                    }
+                    self.pending.clear();
+                }
+                KeyCode::Char('n') | KeyCode::Char(' ') => debugger.next(),
+                KeyCode::Char('p') | KeyCode::Backspace => debugger.previous(),
+                KeyCode::Home => debugger.jump(0),
+                KeyCode::End => debugger.jump(debugger.last_turn()),
+                KeyCode::Up => debugger.move_cursor(-1, 0),
+                KeyCode::Down => debugger.move_cursor(1, 0),
+                KeyCode::Left => debugger.move_cursor(0, -1),
+                KeyCode::Right => debugger.move_cursor(0, 1),
+                KeyCode::Char('v') => debugger.show_visited = !debugger.show_visited,
+                KeyCode::Char('o') if !debugger.toggle_obstruction() => {
+                    self.message = Some("Obstructions can only go on empty cells".to_string());
+                }
+                _ => (),
+            }
+        }
+    }
+
+    fn draw(&self, frame: &mut Frame) {
+        let [map, side] =
+            Layout::horizontal([Constraint::Min(0), Constraint::Length(36)]).areas(frame.area());
+        self.draw_map(frame, map);
+        self.draw_status(frame, side);
+    }
+
+    fn draw_map(&self, frame: &mut Frame, area: Rect) {
+        let debugger = &self.debugger;
+        let mut grid = debugger.grid();
+        if let Some(step) = debugger.guard() {
+            grid[(step.row, step.column)] = step.direction.to_char();
+        }
+        let visited = debugger.visited();
+
+        let lines = grid
+            .rows()
+            .into_iter()
+            .enumerate()
+            .map(|(row, cells)| {
+                Line::from(
+                    cells
+                        .iter()
+                        .enumerate()
+                        .map(|(column, &cell)| {
+                            let mut style = cell_style(cell);
+                            if debugger.show_visited && visited[(row, column)] {
+                                style = style.bg(Color::DarkGray);
+                            }
+                            if (row, column) == debugger.cursor() {
+                                style = style.add_modifier(Modifier::REVERSED);
+                            }
+                            Span::styled(cell.to_string(), style)
+                        })
+                        .collect::<Vec<Span>>(),
+                )
+            })
+            .collect::<Vec<Line>>();
+
+        // Keep the cursor in view on maps larger than the terminal
+        let (row, column) = debugger.cursor();
+        let offset = |position: usize, size: u16| {
+            u16::try_from(position.saturating_sub(usize::from(size.saturating_sub(2)) / 2)).unwrap_or(u16::MAX)
+        };
+        let paragraph = Paragraph::new(lines)
+            .block(Block::bordered().title(" Day 6 guard "))
+            .scroll((offset(row, area.height), offset(column, area.width)));
+        frame.render_widget(paragraph, area);
+    }
+
+    fn draw_status(&self, frame: &mut Frame, area: Rect) {
+        let debugger = &self.debugger;
+        let mut lines = vec![
+            Line::from(format!("Turn {} of {}", debugger.turn(), debugger.last_turn())),
+            Line::from(match debugger.guard() {
+                Some(step) => format!("Guard at {},{} facing {:?}", step.row, step.column, step.direction),
+                None => "Guard has not moved".to_string(),
+            }),
+            Line::from(format!("Cursor at {},{}", debugger.cursor().0, debugger.cursor().1)),
+            Line::from(format!("Obstructions: {}", debugger.obstructions().len())),
+            match debugger.outcome() {
+                Outcome::Loops => Line::styled("The guard loops", Style::new().fg(Color::Red)),
+                Outcome::Leaves(visited) => {
+                    Line::styled(format!("Leaves after {} cells", visited), Style::new().fg(Color::Green))
+                }
+            },
+            Line::from(""),
+        ];
+        if !self.pending.is_empty() {
+            lines.push(Line::from(format!("Jump to turn {}", self.pending)));
+        }
+        if let Some(message) = &self.message {
+            lines.push(Line::styled(message.as_str(), Style::new().fg(Color::Yellow)));
+        }
+        lines.push(Line::from(""));
+        lines.extend(HELP.iter().map(|(keys, action)| {
+            Line::from(vec![
+                Span::styled(format!("{:>13} ", keys), Style::new().add_modifier(Modifier::BOLD)),
+                Span::raw(*action),
+            ])
+        }));
+
+        frame.render_widget(Paragraph::new(lines).block(Block::bordered().title(" Status ")), area);
+    }
+}
+
+fn cell_style(cell: char) -> Style {
+    match cell {
+        '#' => Style::new().fg(Color::White).add_modifier(Modifier::BOLD),
+        '|' | '-' => Style::new().fg(Color::Yellow),
+        '+' => Style::new().fg(Color::LightYellow).add_modifier(Modifier::BOLD),
+        'O' => Style::new().fg(Color::Red).add_modifier(Modifier::BOLD),
+        '^' | 'v' | '<' | '>' => Style::new().fg(Color::Green).add_modifier(Modifier::BOLD),
+        _ => Style::new().fg(Color::DarkGray),
+    }
+}
@@ -0,0 +1,63 @@
+use std::fs;
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use cargo_aoc::examples;
+use clap::Args;
+
+use crate::DEFAULT_YEAR;
+
+#[derive(Args, Debug)]
+pub struct ExamplesArgs {
+    /// Event year
+    #[arg(short, long, default_value_t = DEFAULT_YEAR)]
+    year: u16,
+
+    /// Day the page describes
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+
+    /// Puzzle description page saved from the browser
+    page: PathBuf,
+
+    /// Directory holding `<year>/dayN.txt` fixtures and the expected answers registry
+    #[arg(long, default_value = "test_input")]
+    fixtures: PathBuf,
+}
+
+pub fn extract_examples(args: &ExamplesArgs) -> ExitCode {
+    let html = match fs::read_to_string(&args.page) {
+        Ok(html) => html,
+        Err(e) => {
+            eprintln!("Failed to read {:?}: {}", args.page, e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let extracted = examples::extract(&html);
+    if extracted.inputs.is_empty() {
+        eprintln!("No examples found in {:?}", args.page);
+        return ExitCode::FAILURE;
+    }
+
+    match examples::write_fixtures(&extracted, &args.fixtures, args.year, args.day) {
+        Ok(written) => {
+            for path in written {
+                println!("Wrote {}", path.display());
+            }
+            for answer in &extracted.answers {
+                println!(
+                    "Part {}: {} expected for {}",
+                    answer.part,
+                    answer.answer,
+                    examples::fixture_name(args.day, answer.input)
+                );
+            }
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("Failed to write fixtures: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
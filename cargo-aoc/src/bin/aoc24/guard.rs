@@ -0,0 +1,124 @@
+use std::fs;
+#[cfg(feature = "render")]
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use cargo_aoc::y2024::day6::{self, render};
+use clap::Args;
+
+use crate::SiteArgs;
+
+#[derive(Args, Debug)]
+pub struct GuardArgs {
+    /// Puzzle input file, the cached 2024 day 6 input is used when omitted
+    #[arg(short, long)]
+    input: Option<String>,
+
+    #[command(flatten)]
+    site: SiteArgs,
+
+    /// Extra obstruction placed before the guard starts walking, as `ROW,COLUMN`
+    #[arg(short, long, value_parser = parse_position)]
+    obstruction: Option<(usize, usize)>,
+
+    /// Prints the map without colours
+    #[arg(long)]
+    no_colour: bool,
+
+    /// Directory to write one PNG per turn of the walk to
+    #[cfg(feature = "render")]
+    #[arg(long)]
+    png_dir: Option<PathBuf>,
+
+    /// File to write an animation of the walk to
+    #[cfg(feature = "render")]
+    #[arg(long)]
+    gif: Option<PathBuf>,
+
+    /// Pixels per map cell in exported images
+    #[cfg(feature = "render")]
+    #[arg(long, default_value_t = 4)]
+    scale: usize,
+
+    /// Time each frame of the animation is shown for, in hundredths of a second
+    #[cfg(feature = "render")]
+    #[arg(long, default_value_t = 10)]
+    delay: u16,
+}
+
+fn parse_position(value: &str) -> Result<(usize, usize), String> {
+    let (row, column) = value
+        .split_once(',')
+        .ok_or_else(|| format!("Expected ROW,COLUMN but got {:?}", value))?;
+    let parse = |n: &str| n.trim().parse::<usize>().map_err(|e| format!("{:?}: {}", n, e));
+    Ok((parse(row)?, parse(column)?))
+}
+
+pub fn guard(args: &GuardArgs) -> ExitCode {
+    let input = match &args.input {
+        Some(path) => fs::read_to_string(path).map_err(|e| format!("Failed to read input {:?}: {}", path, e)),
+        None => args.site.input_cache().resolve(2024, 6).map_err(|e| e.to_string()),
+    };
+    let input = match input {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("{}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let (mut board, mut guard) = day6::input_generator_day6(&input);
+    if let Some(position) = args.obstruction {
+        match board.get(position) {
+            Some('.') => board[position] = '#',
+            Some(cell) => {
+                eprintln!("Cannot place an obstruction on {:?} at {:?}", cell, position);
+                return ExitCode::FAILURE;
+            }
+            None => {
+                eprintln!("{:?} is outside the map", position);
+                return ExitCode::FAILURE;
+            }
+        }
+    }
+
+    let looping = guard.walk(&board);
+
+    let grid = render::overlay(&board, &guard.steps(), args.obstruction);
+    if args.no_colour {
+        print!("{}", render::to_text(&grid));
+    } else {
+        print!("{}", render::to_ansi(&grid));
+    }
+    if looping {
+        println!("The guard is stuck in a loop");
+    } else {
+        println!("The guard leaves the map after visiting {} cells", guard.visited_count());
+    }
+
+    #[cfg(feature = "render")]
+    if let Err(e) = export(args, &board, &guard) {
+        eprintln!("Failed to export images: {}", e);
+        return ExitCode::FAILURE;
+    }
+
+    ExitCode::SUCCESS
+}
+
+#[cfg(feature = "render")]
+fn export(args: &GuardArgs, board: &cargo_aoc::grid::Grid<char>, guard: &day6::Guard) -> std::io::Result<()> {
+    if args.png_dir.is_none() && args.gif.is_none() {
+        return Ok(());
+    }
+    let frames = render::frames(board, guard, args.obstruction);
+    if let Some(dir) = &args.png_dir {
+        fs::create_dir_all(dir)?;
+        for (i, frame) in frames.iter().enumerate() {
+            render::write_png(frame, args.scale, &dir.join(format!("frame{:04}.png", i)))?;
+        }
+    }
+    if let Some(path) = &args.gif {
+        render::write_gif(&frames, args.scale, args.delay, path)?;
+    }
+    Ok(())
+}
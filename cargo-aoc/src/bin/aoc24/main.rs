@@ -0,0 +1,108 @@
+#[cfg(feature = "tui")]
+mod debug;
+mod examples;
+mod guard;
+mod new_day;
+mod profile;
+mod run;
+mod submit;
+mod word_search;
+
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use cargo_aoc::inputs::{HttpFetcher, InputCache};
+use cargo_aoc::profile::CountingAllocator;
+use clap::{Args, Parser, Subcommand};
+
+#[cfg(feature = "tui")]
+use crate::debug::DebugArgs;
+use crate::examples::ExamplesArgs;
+use crate::guard::GuardArgs;
+use crate::new_day::NewDayArgs;
+use crate::profile::ProfileArgs;
+use crate::run::RunArgs;
+use crate::submit::SubmitArgs;
+use crate::word_search::WordSearchArgs;
+
+/// Year used when none is given on the command line
+const DEFAULT_YEAR: u16 = 2024;
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+/// Runs the Advent of Code solutions
+#[derive(Parser, Debug)]
+#[command(version, about, args_conflicts_with_subcommands = true)]
+struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    #[command(flatten)]
+    run: RunArgs,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Runs solutions and prints their answers, the default when no command is given
+    Run(RunArgs),
+    /// Runs every step of each day repeatedly and reports timings and peak heap as Markdown
+    Profile(ProfileArgs),
+    /// Submits an answer to the puzzle website
+    Submit(SubmitArgs),
+    /// Extracts example fixtures and their answers from a saved puzzle page
+    ExtractExamples(ExamplesArgs),
+    /// Generates the module, registration and fixture for a new day
+    NewDay(NewDayArgs),
+    /// Draws the 2024 day 6 guard's patrol, optionally exporting it as images
+    Guard(GuardArgs),
+    /// Shows the 2024 day 4 word search with only the letters of each match kept
+    WordSearch(WordSearchArgs),
+    /// Steps through the 2024 day 6 guard's walk interactively, placing obstructions live
+    #[cfg(feature = "tui")]
+    Debug(DebugArgs),
+}
+
+/// Options for reaching the puzzle website and caching what it returns
+#[derive(Args, Debug)]
+struct SiteArgs {
+    /// Input cache holding `<year>/dayN.txt`, used when no input is given
+    #[arg(long, default_value = "input")]
+    input_cache: PathBuf,
+
+    /// Session token used to download inputs and submit answers
+    #[arg(long, env = "AOC_SESSION", hide_env_values = true)]
+    session: Option<String>,
+
+    /// Base URL of the puzzle website
+    #[arg(long, env = "AOC_BASE_URL", default_value = "https://adventofcode.com")]
+    base_url: String,
+}
+
+impl SiteArgs {
+    /// Input cache that downloads missing inputs when a session token is available
+    fn input_cache(&self) -> InputCache {
+        let cache = InputCache::new(&self.input_cache);
+        match &self.session {
+            Some(session) => cache.with_fetcher(Box::new(HttpFetcher::new(&self.base_url, session))),
+            None => cache,
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    match &cli.command {
+        None => run::run(&cli.run),
+        Some(Command::Run(args)) => run::run(args),
+        Some(Command::Profile(args)) => profile::profile(args),
+        Some(Command::Submit(args)) => submit::submit(args),
+        Some(Command::ExtractExamples(args)) => examples::extract_examples(args),
+        Some(Command::NewDay(args)) => new_day::new_day(args),
+        Some(Command::Guard(args)) => guard::guard(args),
+        Some(Command::WordSearch(args)) => word_search::word_search(args),
+        #[cfg(feature = "tui")]
+        Some(Command::Debug(args)) => debug::debug(args),
+    }
+}
@@ -0,0 +1,37 @@
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use cargo_aoc::scaffold;
+use clap::Args;
+
+use crate::DEFAULT_YEAR;
+
+#[derive(Args, Debug)]
+pub struct NewDayArgs {
+    /// Event year
+    #[arg(short, long, default_value_t = DEFAULT_YEAR)]
+    year: u16,
+
+    /// Day to generate
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+
+    /// Root of the crate the day is added to
+    #[arg(long, default_value = env!("CARGO_MANIFEST_DIR"))]
+    crate_root: PathBuf,
+}
+
+pub fn new_day(args: &NewDayArgs) -> ExitCode {
+    match scaffold::new_day(&args.crate_root, args.year, args.day) {
+        Ok(written) => {
+            for path in written {
+                println!("Wrote {}", path.display());
+            }
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("{}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
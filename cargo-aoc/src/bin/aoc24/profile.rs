@@ -0,0 +1,72 @@
+use std::fs;
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use cargo_aoc::profile::{self, DayProfile};
+use clap::Args;
+
+use crate::{SiteArgs, DEFAULT_YEAR};
+
+#[derive(Args, Debug)]
+pub struct ProfileArgs {
+    /// Event year
+    #[arg(short, long, default_value_t = DEFAULT_YEAR)]
+    year: u16,
+
+    /// Day to profile, all days are profiled when omitted
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: Option<u8>,
+
+    /// Number of times each step is run
+    #[arg(short, long, default_value_t = 10)]
+    runs: usize,
+
+    #[command(flatten)]
+    site: SiteArgs,
+
+    /// File to write the Markdown table to, defaults to stdout
+    #[arg(short, long)]
+    output: Option<PathBuf>,
+}
+
+pub fn profile(args: &ProfileArgs) -> ExitCode {
+    let registry = cargo_aoc::registry();
+    let puzzles = registry
+        .year(args.year)
+        .filter(|p| args.day.is_none_or(|d| p.day() == d))
+        .collect::<Vec<_>>();
+    if puzzles.is_empty() {
+        eprintln!("No solution for the requested day of {}", args.year);
+        return ExitCode::FAILURE;
+    }
+
+    let cache = args.site.input_cache();
+    let mut profiles: Vec<DayProfile> = Vec::new();
+    let mut failed = false;
+    for puzzle in puzzles {
+        match cache.resolve(args.year, puzzle.day()) {
+            Ok(input) => profiles.push(profile::profile(puzzle, &input, args.runs)),
+            Err(e) => {
+                eprintln!("Day {}: {}", puzzle.day(), e);
+                failed = true;
+            }
+        }
+    }
+
+    let table = profile::markdown_table(&profiles);
+    match &args.output {
+        Some(path) => {
+            if let Err(e) = fs::write(path, table) {
+                eprintln!("Failed to write {:?}: {}", path, e);
+                return ExitCode::FAILURE;
+            }
+        }
+        None => print!("{}", table),
+    }
+
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
@@ -0,0 +1,199 @@
+use std::fs;
+use std::io::{self, Read};
+use std::path::Path;
+use std::process::ExitCode;
+use std::time::{Duration, Instant};
+
+use cargo_aoc::answer::Answer;
+use cargo_aoc::inputs::InputCache;
+use clap::{Args, ValueEnum};
+use serde_json::json;
+
+use crate::{SiteArgs, DEFAULT_YEAR};
+
+#[derive(Args, Debug)]
+pub struct RunArgs {
+    /// Event year
+    #[arg(short, long, default_value_t = DEFAULT_YEAR)]
+    year: u16,
+
+    /// Day to run, all days are run when omitted
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: Option<u8>,
+
+    /// Part to run, both parts are run when omitted
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+
+    /// Puzzle input file, or `-` to read from stdin
+    #[arg(short, long, requires = "day")]
+    input: Option<String>,
+
+    #[command(flatten)]
+    site: SiteArgs,
+
+    /// Expected answer, the process exits with a failure status when it does not match
+    #[arg(short, long, requires_all = ["day", "part"])]
+    expected: Option<String>,
+
+    /// Output format
+    #[arg(short, long, value_enum, default_value_t = Format::Table)]
+    format: Format,
+}
+
+#[derive(Clone, Copy, Debug, ValueEnum)]
+enum Format {
+    Table,
+    Json,
+}
+
+struct Outcome {
+    day: u8,
+    part: u8,
+    answer: Answer,
+    parse_time: Duration,
+    solve_time: Duration,
+    expected: Option<String>,
+}
+
+impl Outcome {
+    fn is_mismatch(&self) -> bool {
+        self.expected.as_ref().is_some_and(|e| !self.answer.matches(e))
+    }
+}
+
+fn read_input(args: &RunArgs, cache: &InputCache, day: u8) -> Result<String, String> {
+    match args.input.as_deref() {
+        Some("-") => {
+            let mut input = String::new();
+            io::stdin()
+                .read_to_string(&mut input)
+                .map_err(|e| format!("Failed to read input from stdin: {}", e))?;
+            Ok(input)
+        }
+        Some(path) => read_file(Path::new(path)),
+        None => cache.resolve(args.year, day).map_err(|e| e.to_string()),
+    }
+}
+
+fn read_file(path: &Path) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|e| format!("Failed to read input {:?}: {}", path, e))
+}
+
+fn print_table(outcomes: &[Outcome]) {
+    // Grid answers span several lines, so they are printed below their row
+    let column = |answer: &Answer| match answer {
+        Answer::Grid(_) => "(grid)".to_string(),
+        _ => answer.to_string(),
+    };
+    let answer_width = outcomes
+        .iter()
+        .map(|o| column(&o.answer).len())
+        .chain(std::iter::once("Answer".len()))
+        .max()
+        .unwrap_or_default();
+
+    println!("Day  Part  {:<answer_width$}  {:<12}  Solve", "Answer", "Parse");
+    for outcome in outcomes {
+        let status = match &outcome.expected {
+            Some(expected) if outcome.is_mismatch() => format!("  (expected {})", expected.trim()),
+            Some(_) => "  (ok)".to_string(),
+            None => String::new(),
+        };
+        println!(
+            "{:>3}  {:>4}  {:<answer_width$}  {:<12}  {:?}{}",
+            outcome.day,
+            outcome.part,
+            column(&outcome.answer),
+            format!("{:?}", outcome.parse_time),
+            outcome.solve_time,
+            status
+        );
+        if let Answer::Grid(grid) = &outcome.answer {
+            println!("{}", grid);
+        }
+    }
+}
+
+fn print_json(outcomes: &[Outcome]) {
+    let results = outcomes
+        .iter()
+        .map(|o| {
+            json!({
+                "day": o.day,
+                "part": o.part,
+                "answer": o.answer.to_string(),
+                "parse_ns": o.parse_time.as_nanos() as u64,
+                "solve_ns": o.solve_time.as_nanos() as u64,
+                "expected": o.expected.as_deref().map(str::trim),
+                "correct": o.expected.as_ref().map(|_| !o.is_mismatch()),
+            })
+        })
+        .collect::<Vec<_>>();
+    println!("{}", serde_json::to_string_pretty(&results).expect("Failed to serialise results"));
+}
+
+pub fn run(args: &RunArgs) -> ExitCode {
+    let registry = cargo_aoc::registry();
+    let puzzles = registry
+        .year(args.year)
+        .filter(|p| args.day.is_none_or(|d| p.day() == d))
+        .collect::<Vec<_>>();
+    if puzzles.is_empty() {
+        eprintln!("No solution for the requested day of {}", args.year);
+        return ExitCode::FAILURE;
+    }
+
+    let cache = args.site.input_cache();
+
+    let mut outcomes = Vec::new();
+    let mut failed = false;
+    for puzzle in puzzles {
+        let day = puzzle.day();
+        let input = match read_input(args, &cache, day) {
+            Ok(input) => input,
+            Err(e) => {
+                eprintln!("Day {}: {}", day, e);
+                failed = true;
+                continue;
+            }
+        };
+
+        let start = Instant::now();
+        let parsed = puzzle.parse_input(&input);
+        let parse_time = start.elapsed();
+
+        for part in [1, 2].into_iter().filter(|p| args.part.is_none_or(|part| *p == part)) {
+            let start = Instant::now();
+            let answer = puzzle.solve(parsed.as_ref(), part);
+            let solve_time = start.elapsed();
+
+            let Some(answer) = answer else {
+                if args.part.is_some() {
+                    eprintln!("Day {}: part {} is not solved", day, part);
+                    failed = true;
+                }
+                continue;
+            };
+            outcomes.push(Outcome {
+                day,
+                part,
+                answer,
+                parse_time,
+                solve_time,
+                expected: args.expected.clone(),
+            });
+        }
+    }
+
+    match args.format {
+        Format::Table => print_table(&outcomes),
+        Format::Json => print_json(&outcomes),
+    }
+
+    if failed || outcomes.iter().any(Outcome::is_mismatch) {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
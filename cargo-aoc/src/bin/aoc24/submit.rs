@@ -0,0 +1,94 @@
+use std::process::ExitCode;
+use std::thread;
+
+use cargo_aoc::answer::Answer;
+use cargo_aoc::submit::{SubmitClient, SubmitError, Verdict};
+use clap::Args;
+
+use crate::{SiteArgs, DEFAULT_YEAR};
+
+#[derive(Args, Debug)]
+pub struct SubmitArgs {
+    /// Event year
+    #[arg(short, long, default_value_t = DEFAULT_YEAR)]
+    year: u16,
+
+    /// Day the answer is for
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+
+    /// Part the answer is for
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: u8,
+
+    /// Answer to submit, computed from the cached input when omitted
+    answer: Option<String>,
+
+    /// Sleep through a cooldown instead of giving up
+    #[arg(short, long)]
+    wait: bool,
+
+    #[command(flatten)]
+    site: SiteArgs,
+}
+
+fn solve(args: &SubmitArgs) -> Result<Answer, String> {
+    let registry = cargo_aoc::registry();
+    let puzzle = registry
+        .get(args.year, args.day)
+        .ok_or_else(|| format!("No solution for day {} of {}", args.day, args.year))?;
+    let input = args
+        .site
+        .input_cache()
+        .resolve(args.year, args.day)
+        .map_err(|e| e.to_string())?;
+    puzzle
+        .run(&input, args.part)
+        .ok_or_else(|| format!("Part {} of day {} is not solved", args.part, args.day))
+}
+
+pub fn submit(args: &SubmitArgs) -> ExitCode {
+    let Some(session) = &args.site.session else {
+        eprintln!("A session token is required to submit answers, set AOC_SESSION");
+        return ExitCode::FAILURE;
+    };
+
+    let answer = match &args.answer {
+        Some(answer) => Answer::from(answer.trim()),
+        None => match solve(args) {
+            Ok(answer) => answer,
+            Err(e) => {
+                eprintln!("{}", e);
+                return ExitCode::FAILURE;
+            }
+        },
+    };
+
+    let client = SubmitClient::new(
+        &args.site.base_url,
+        session,
+        args.site.input_cache.join("submissions"),
+    );
+
+    loop {
+        println!("Submitting {} for day {} part {}", answer, args.day, args.part);
+        match client.submit(args.year, args.day, args.part, &answer) {
+            Ok(Verdict::Correct) => {
+                println!("{}", Verdict::Correct);
+                return ExitCode::SUCCESS;
+            }
+            Ok(Verdict::Wait(left)) | Err(SubmitError::CoolingDown(left)) if args.wait => {
+                println!("Cooling down, waiting {:?}", left);
+                thread::sleep(left);
+            }
+            Ok(verdict) => {
+                println!("{}", verdict);
+                return ExitCode::FAILURE;
+            }
+            Err(e) => {
+                eprintln!("{}", e);
+                return ExitCode::FAILURE;
+            }
+        }
+    }
+}
@@ -0,0 +1,47 @@
+use std::fs;
+use std::process::ExitCode;
+
+use cargo_aoc::answer::Answer;
+use cargo_aoc::y2024::day4;
+use clap::Args;
+
+use crate::SiteArgs;
+
+#[derive(Args, Debug)]
+pub struct WordSearchArgs {
+    /// Puzzle input file, the cached 2024 day 4 input is used when omitted
+    #[arg(short, long)]
+    input: Option<String>,
+
+    #[command(flatten)]
+    site: SiteArgs,
+
+    /// Shows the crossed `MAS` matches of part 2 instead of the `XMAS` ones of part 1
+    #[arg(short = 'x', long)]
+    cross: bool,
+}
+
+pub fn word_search(args: &WordSearchArgs) -> ExitCode {
+    let input = match &args.input {
+        Some(path) => fs::read_to_string(path).map_err(|e| format!("Failed to read input {:?}: {}", path, e)),
+        None => args.site.input_cache().resolve(2024, 4).map_err(|e| e.to_string()),
+    };
+    let input = match input {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("{}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let board = day4::input_generator(&input);
+    let matches = if args.cross {
+        day4::find_x_matches("MAS", &board)
+    } else {
+        day4::find_word_matches("XMAS", &board)
+    };
+    println!("{}", Answer::grid(&day4::render_matches(&board, &matches)));
+    println!("{} matches", matches.len());
+
+    ExitCode::SUCCESS
+}
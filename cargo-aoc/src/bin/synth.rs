@@ -0,0 +1,62 @@
+use std::fs;
+use std::io::{self, Write};
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use cargo_aoc::{synth, y2024};
+use clap::Parser;
+
+/// Generates large, valid puzzle inputs for benchmarking and stress testing
+#[derive(Parser, Debug)]
+#[command(version, about)]
+struct Args {
+    /// Event year
+    #[arg(short, long, default_value_t = 2024)]
+    year: u16,
+
+    /// Day to generate an input for
+    #[arg(short, long)]
+    day: u8,
+
+    /// Seed for the generator, the same seed always produces the same input
+    #[arg(short, long, default_value_t = 2024)]
+    seed: u64,
+
+    /// Size of the input relative to a real puzzle input
+    #[arg(long, default_value_t = 1)]
+    scale: usize,
+
+    /// Allow 2024 day 6 maps on which the guard walks in a loop
+    #[arg(long)]
+    allow_loops: bool,
+
+    /// File to write the input to, defaults to stdout
+    #[arg(short, long)]
+    output: Option<PathBuf>,
+}
+
+fn main() -> ExitCode {
+    let args = Args::parse();
+
+    let input = if (args.year, args.day) == (2024, 6) && args.allow_loops {
+        Some(y2024::synth::day6(args.seed, synth::grid_side(130, args.scale), false))
+    } else {
+        synth::generate(args.year, args.day, args.seed, args.scale)
+    };
+
+    let Some(input) = input else {
+        eprintln!("No synthetic input generator for day {} of {}", args.day, args.year);
+        return ExitCode::FAILURE;
+    };
+
+    let result = match &args.output {
+        Some(path) => fs::write(path, input),
+        None => io::stdout().write_all(input.as_bytes()),
+    };
+
+    if let Err(e) = result {
+        eprintln!("Failed to write input: {}", e);
+        return ExitCode::FAILURE;
+    }
+    ExitCode::SUCCESS
+}
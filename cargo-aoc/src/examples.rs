@@ -0,0 +1,225 @@
+//! Example inputs and answers extracted from a saved puzzle description page.
+//!
+//! Each part of a puzzle is an `<article class="day-desc">`. Examples are its `<pre><code>`
+//! blocks, and the answer a part's example should produce is the last emphasised code
+//! (`<code><em>..</em></code>`) in that part's article. The answer is paired with the last example
+//! shown before it, so a part two that reuses the part one example gets the same fixture.
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use regex::Regex;
+use tracing::info;
+
+use crate::fixtures::{self, ExpectedAnswer};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartExample {
+    pub part: u8,
+    /// Index into [`Examples::inputs`]
+    pub input: usize,
+    pub answer: String,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Examples {
+    /// Every distinct example block, in page order
+    pub inputs: Vec<String>,
+    pub answers: Vec<PartExample>,
+}
+
+/// Extracts the examples and their answers from a puzzle page
+pub fn extract(html: &str) -> Examples {
+    let article = Regex::new(r#"(?s)<article class="day-desc">(.*?)</article>"#).unwrap();
+    let block = Regex::new(r"(?s)<pre><code>(.*?)</code></pre>").unwrap();
+    let emphasised =
+        Regex::new(r"(?s)<code><em>(.*?)</em></code>|<em><code>(.*?)</code></em>").unwrap();
+
+    let mut examples = Examples::default();
+    // Index of the latest example block seen so far, carried across articles
+    let mut latest = None;
+
+    for (part, article) in article.captures_iter(html).enumerate() {
+        let article = article.get(1).unwrap().as_str();
+
+        let blocks = block
+            .captures_iter(article)
+            .map(|c| {
+                let m = c.get(1).unwrap();
+                (m.start(), unescape(&strip_tags(m.as_str())))
+            })
+            .collect::<Vec<(usize, String)>>();
+
+        let answer = emphasised.captures_iter(article).last().map(|c| {
+            let m = c.get(1).or_else(|| c.get(2)).unwrap();
+            (m.start(), unescape(&strip_tags(m.as_str())))
+        });
+
+        let mut answer_input = latest;
+        for (start, input) in blocks {
+            let index = match examples.inputs.iter().position(|i| *i == input) {
+                Some(index) => index,
+                None => {
+                    examples.inputs.push(input);
+                    examples.inputs.len() - 1
+                }
+            };
+            if answer.as_ref().is_none_or(|(position, _)| start < *position) {
+                answer_input = Some(index);
+            }
+            latest = Some(index);
+        }
+
+        if let (Some((_, answer)), Some(input)) = (answer, answer_input) {
+            examples.answers.push(PartExample {
+                part: part as u8 + 1,
+                input,
+                answer,
+            });
+        }
+    }
+
+    examples
+}
+
+/// Fixture name of the `index`th example of a day: `day<N>.txt` for the first, then
+/// `day<N>_2.txt` and so on
+pub fn fixture_name(day: u8, index: usize) -> String {
+    match index {
+        0 => format!("day{}.txt", day),
+        _ => format!("day{}_{}.txt", day, index + 1),
+    }
+}
+
+/// Writes the examples as fixtures in `root/<year>/` and their answers to the expected answers
+/// registry in `root`. Existing fixtures are never overwritten, so hand written ones survive; the
+/// paths of the fixtures actually written are returned.
+pub fn write_fixtures(
+    examples: &Examples,
+    root: &Path,
+    year: u16,
+    day: u8,
+) -> io::Result<Vec<PathBuf>> {
+    let dir = root.join(year.to_string());
+    fs::create_dir_all(&dir)?;
+
+    let mut written = Vec::new();
+    for (index, input) in examples.inputs.iter().enumerate() {
+        let path = dir.join(fixture_name(day, index));
+        if path.exists() {
+            info!("Keeping existing fixture {:?}", path);
+            continue;
+        }
+        // Fixtures are stored without the trailing newline of the code block
+        fs::write(&path, input.trim_end_matches('\n'))?;
+        written.push(path);
+    }
+
+    let entries = examples
+        .answers
+        .iter()
+        .map(|a| ExpectedAnswer {
+            year,
+            day,
+            part: a.part,
+            fixture: fixture_name(day, a.input),
+            answer: a.answer.clone(),
+        })
+        .collect::<Vec<ExpectedAnswer>>();
+    fixtures::add_expected_answers(root, &entries)?;
+
+    Ok(written)
+}
+
+fn strip_tags(html: &str) -> String {
+    Regex::new(r"<[^>]*>").unwrap().replace_all(html, "").into_owned()
+}
+
+fn unescape(html: &str) -> String {
+    html.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&#x27;", "'")
+        .replace("&amp;", "&")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    const PAGE: &str = r#"<html><body><main>
+<article class="day-desc"><h2>--- Day 3: Mull It Over ---</h2>
+<p>For example, consider the following section of corrupted memory:</p>
+<pre><code>x<em>mul(2,4)</em>%&amp;mul[3,7]!@^do_not_<em>mul(5,5)</em>+mul(32,64]then(<em>mul(11,8)</em><em>mul(8,5)</em>)</code></pre>
+<p>Adding up the result of each instruction produces <code><em>161</em></code> (<code>2*4 + 5*5 + 11*8 + 8*5</code>).</p>
+</article>
+<p>Your puzzle answer was <code>12345</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2>
+<p>This time, the corrupted memory is different:</p>
+<pre><code>xmul(2,4)&amp;mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))
+</code></pre>
+<p>This time, the sum of the results is <em><code>48</code></em>.</p>
+</article>
+</main></body></html>"#;
+
+    #[test]
+    fn test_extract() {
+        let examples = extract(PAGE);
+
+        assert_eq!(
+            examples.inputs,
+            vec![
+                "xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))",
+                "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))\n",
+            ]
+        );
+        assert_eq!(
+            examples.answers,
+            vec![
+                PartExample {
+                    part: 1,
+                    input: 0,
+                    answer: "161".to_string()
+                },
+                PartExample {
+                    part: 2,
+                    input: 1,
+                    answer: "48".to_string()
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_part_two_reuses_example() {
+        let page = r#"<article class="day-desc"><pre><code>3   4
+4   3</code></pre><p>Total <code><em>11</em></code></p></article>
+<article class="day-desc"><p>Using the same example, <code><em>31</em></code></p></article>"#;
+        let examples = extract(page);
+
+        assert_eq!(examples.inputs.len(), 1);
+        assert_eq!(examples.answers[1].input, 0);
+        assert_eq!(examples.answers[1].answer, "31");
+    }
+
+    #[test]
+    fn test_write_fixtures_keeps_existing() {
+        let dir = TempDir::new().unwrap();
+        fs::create_dir_all(dir.path().join("2024")).unwrap();
+        fs::write(dir.path().join("2024/day3.txt"), "hand written").unwrap();
+
+        let written = write_fixtures(&extract(PAGE), dir.path(), 2024, 3).unwrap();
+
+        assert_eq!(written, vec![dir.path().join("2024/day3_2.txt")]);
+        assert_eq!(fs::read_to_string(dir.path().join("2024/day3.txt")).unwrap(), "hand written");
+        assert!(fs::read_to_string(&written[0]).unwrap().ends_with("mul(8,5))"));
+
+        let expected = fixtures::expected_answers(dir.path()).unwrap();
+        assert_eq!(expected.len(), 2);
+        assert_eq!(expected[1].fixture, "day3_2.txt");
+        assert_eq!(expected[1].answer, "48");
+    }
+}
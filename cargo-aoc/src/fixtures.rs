@@ -0,0 +1,141 @@
+//! Example inputs under `test_input/<year>/` and the registry of their expected answers in
+//! `test_input/expected_answers.tsv`.
+//!
+//! The registry has one tab separated `year day part fixture answer` entry per line, so every
+//! example with a known answer is checked against the solver registry by a single test.
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Name of the expected answers registry inside a fixtures directory
+pub const EXPECTED_ANSWERS: &str = "expected_answers.tsv";
+
+/// The `test_input` directory of this crate
+pub fn root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("test_input")
+}
+
+pub fn path(year: u16, name: &str) -> PathBuf {
+    root().join(year.to_string()).join(name)
+}
+
+/// Reads an example input, panicking with the path if it is missing
+pub fn load(year: u16, name: &str) -> String {
+    let path = path(year, name);
+    fs::read_to_string(&path).unwrap_or_else(|e| panic!("Failed to read fixture {:?}: {}", path, e))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExpectedAnswer {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    /// File name relative to `test_input/<year>/`
+    pub fixture: String,
+    pub answer: String,
+}
+
+impl ExpectedAnswer {
+    fn parse(line: &str) -> Option<Self> {
+        let mut fields = line.split('\t');
+        let entry = Self {
+            year: fields.next()?.parse().ok()?,
+            day: fields.next()?.parse().ok()?,
+            part: fields.next()?.parse().ok()?,
+            fixture: fields.next()?.to_string(),
+            answer: fields.next()?.to_string(),
+        };
+        fields.next().is_none().then_some(entry)
+    }
+
+    fn to_line(&self) -> String {
+        format!("{}\t{}\t{}\t{}\t{}", self.year, self.day, self.part, self.fixture, self.answer)
+    }
+}
+
+/// Reads the expected answers registry in `root`. Blank lines and lines starting with `#` are
+/// ignored.
+pub fn expected_answers(root: &Path) -> io::Result<Vec<ExpectedAnswer>> {
+    let contents = match fs::read_to_string(root.join(EXPECTED_ANSWERS)) {
+        Ok(contents) => contents,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(e),
+    };
+
+    contents
+        .lines()
+        .filter(|line| !line.trim().is_empty() && !line.starts_with('#'))
+        .map(|line| {
+            ExpectedAnswer::parse(line).ok_or_else(|| {
+                io::Error::new(io::ErrorKind::InvalidData, format!("Bad entry: {:?}", line))
+            })
+        })
+        .collect()
+}
+
+/// Adds entries to the registry in `root`, replacing any entry for the same year, day, part and
+/// fixture, and keeping the registry sorted
+pub fn add_expected_answers(root: &Path, entries: &[ExpectedAnswer]) -> io::Result<()> {
+    let mut registry = expected_answers(root)?;
+    for entry in entries {
+        registry.retain(|e| {
+            (e.year, e.day, e.part, &e.fixture) != (entry.year, entry.day, entry.part, &entry.fixture)
+        });
+        registry.push(entry.clone());
+    }
+    registry.sort_by(|a, b| {
+        (a.year, a.day, a.part, &a.fixture).cmp(&(b.year, b.day, b.part, &b.fixture))
+    });
+
+    let mut contents = String::from("# year\tday\tpart\tfixture\tanswer\n");
+    for entry in &registry {
+        contents.push_str(&entry.to_line());
+        contents.push('\n');
+    }
+    fs::create_dir_all(root)?;
+    fs::write(root.join(EXPECTED_ANSWERS), contents)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_expected_answers_match_solvers() {
+        let registry = crate::registry();
+        let expected = expected_answers(&root()).unwrap();
+        assert!(!expected.is_empty());
+
+        for entry in expected {
+            let puzzle = registry
+                .get(entry.year, entry.day)
+                .unwrap_or_else(|| panic!("No solver for {:?}", entry));
+            let answer = puzzle
+                .run(&load(entry.year, &entry.fixture), entry.part)
+                .unwrap_or_else(|| panic!("Part not solved for {:?}", entry));
+            assert!(answer.matches(&entry.answer), "Got {} for {:?}", answer, entry);
+        }
+    }
+
+    #[test]
+    fn test_add_expected_answers() {
+        let dir = TempDir::new().unwrap();
+        let entry = |day, part, answer: &str| ExpectedAnswer {
+            year: 2024,
+            day,
+            part,
+            fixture: format!("day{}.txt", day),
+            answer: answer.to_string(),
+        };
+
+        add_expected_answers(dir.path(), &[entry(9, 1, "1928"), entry(2, 1, "2")]).unwrap();
+        add_expected_answers(dir.path(), &[entry(9, 1, "1929"), entry(9, 2, "2858")]).unwrap();
+
+        assert_eq!(
+            expected_answers(dir.path()).unwrap(),
+            vec![entry(2, 1, "2"), entry(9, 1, "1929"), entry(9, 2, "2858")]
+        );
+    }
+}
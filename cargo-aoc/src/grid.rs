@@ -0,0 +1,185 @@
+//! Grid and direction types shared by the puzzles that play out on a map, so moving around one
+//! is a single `step` instead of a set of match arms per direction.
+
+use std::fmt::{Display, Formatter};
+use std::ops::{Index, IndexMut};
+
+use aoc_generators::processors::board_generator::generate_2d_board_char;
+use ndarray::Array2;
+
+/// Row and column of a cell
+pub type Position = (usize, usize);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Direction {
+    /// Every direction, clockwise from up
+    pub const ALL: [Direction; 4] = [Direction::Up, Direction::Right, Direction::Down, Direction::Left];
+
+    /// The direction drawn as `^`, `v`, `<` or `>`
+    pub fn from_char(c: char) -> Option<Direction> {
+        match c {
+            '^' => Some(Direction::Up),
+            'v' => Some(Direction::Down),
+            '<' => Some(Direction::Left),
+            '>' => Some(Direction::Right),
+            _ => None,
+        }
+    }
+
+    pub fn to_char(self) -> char {
+        match self {
+            Direction::Up => '^',
+            Direction::Down => 'v',
+            Direction::Left => '<',
+            Direction::Right => '>',
+        }
+    }
+
+    pub fn is_vertical(&self) -> bool {
+        matches!(self, Direction::Up | Direction::Down)
+    }
+
+    /// Change in row and column when moving one cell this way
+    pub fn offset(self) -> (isize, isize) {
+        match self {
+            Direction::Up => (-1, 0),
+            Direction::Down => (1, 0),
+            Direction::Left => (0, -1),
+            Direction::Right => (0, 1),
+        }
+    }
+
+    pub fn turn_right(self) -> Direction {
+        match self {
+            Direction::Up => Direction::Right,
+            Direction::Right => Direction::Down,
+            Direction::Down => Direction::Left,
+            Direction::Left => Direction::Up,
+        }
+    }
+
+    pub fn turn_left(self) -> Direction {
+        self.turn_right().opposite()
+    }
+
+    pub fn opposite(self) -> Direction {
+        self.turn_right().turn_right()
+    }
+}
+
+/// A rectangular map of cells
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    cells: Array2<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(cells: Array2<T>) -> Self {
+        Self { cells }
+    }
+
+    /// Number of rows and columns
+    pub fn dim(&self) -> (usize, usize) {
+        self.cells.dim()
+    }
+
+    pub fn get(&self, position: Position) -> Option<&T> {
+        self.cells.get(position)
+    }
+
+    /// The neighbouring cell in `direction`, `None` off the edge of the map
+    pub fn step(&self, (row, column): Position, direction: Direction) -> Option<Position> {
+        let (dr, dc) = direction.offset();
+        let position = (row.checked_add_signed(dr)?, column.checked_add_signed(dc)?);
+        self.cells.get(position).map(|_| position)
+    }
+
+    /// The first cell, in reading order, holding a value matching `predicate`
+    pub fn find(&self, predicate: impl Fn(&T) -> bool) -> Option<Position> {
+        self.indexed_iter().find(|(_, value)| predicate(value)).map(|(position, _)| position)
+    }
+
+    pub fn indexed_iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.cells.indexed_iter()
+    }
+
+    pub fn cells(&self) -> &Array2<T> {
+        &self.cells
+    }
+
+    pub fn into_cells(self) -> Array2<T> {
+        self.cells
+    }
+}
+
+impl Grid<char> {
+    /// Reads a map of one character per cell
+    pub fn parse(input: &str) -> Self {
+        Self::new(generate_2d_board_char(input))
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Position) -> &T {
+        &self.cells[position]
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, position: Position) -> &mut T {
+        &mut self.cells[position]
+    }
+}
+
+impl Display for Grid<char> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (i, row) in self.cells.rows().into_iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_direction_turns() {
+        for direction in Direction::ALL {
+            assert_eq!(direction.turn_right().turn_left(), direction);
+            assert_eq!(direction.opposite().opposite(), direction);
+            assert_ne!(direction.turn_right().is_vertical(), direction.is_vertical());
+            assert_eq!(Direction::from_char(direction.to_char()), Some(direction));
+        }
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+        assert_eq!(Direction::Up.turn_left(), Direction::Left);
+        assert_eq!(Direction::from_char('.'), None);
+    }
+
+    #[test]
+    fn test_grid_step() {
+        let grid = Grid::parse("#.\n.@");
+        assert_eq!(grid.dim(), (2, 2));
+        assert_eq!(grid.find(|&c| c == '@'), Some((1, 1)));
+        assert_eq!(grid.step((1, 1), Direction::Up), Some((0, 1)));
+        assert_eq!(grid.step((1, 1), Direction::Right), None);
+        assert_eq!(grid.step((0, 0), Direction::Left), None);
+        assert_eq!(grid[(0, 0)], '#');
+        assert_eq!(grid.to_string(), "#.\n.@");
+    }
+}
@@ -0,0 +1,295 @@
+//! Puzzle inputs resolved by (year, day) from a local cache, laid out like cargo-aoc's
+//! `input/<year>/day<day>.txt`, and fetched through an [`InputFetcher`] when missing.
+
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use tracing::{debug, info};
+
+#[derive(Debug)]
+pub enum InputError {
+    Io(io::Error),
+    /// The input is not cached and there is no fetcher to download it
+    NotCached { year: u16, day: u8 },
+    /// The session token was missing, expired or rejected
+    Unauthorized,
+    /// The puzzle has not been released yet, or does not exist
+    NotAvailable { year: u16, day: u8 },
+    /// The server asked us to slow down
+    RateLimited,
+    /// Any other unexpected HTTP status
+    Http(u16),
+    /// The server could not be reached
+    Transport(String),
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputError::Io(e) => write!(f, "I/O error: {}", e),
+            InputError::NotCached { year, day } => {
+                write!(f, "Input for day {} of {} is not cached and cannot be fetched", day, year)
+            }
+            InputError::Unauthorized => write!(f, "Session token was rejected"),
+            InputError::NotAvailable { year, day } => {
+                write!(f, "Input for day {} of {} is not available yet", day, year)
+            }
+            InputError::RateLimited => write!(f, "Too many requests, try again later"),
+            InputError::Http(status) => write!(f, "Unexpected HTTP status {}", status),
+            InputError::Transport(e) => write!(f, "Failed to reach server: {}", e),
+        }
+    }
+}
+
+impl Error for InputError {}
+
+impl From<io::Error> for InputError {
+    fn from(e: io::Error) -> Self {
+        InputError::Io(e)
+    }
+}
+
+/// Source of puzzle inputs that are not cached yet
+pub trait InputFetcher {
+    fn fetch(&self, year: u16, day: u8) -> Result<String, InputError>;
+}
+
+/// Downloads inputs from `<base_url>/<year>/day/<day>/input` with a session cookie, leaving at
+/// least `min_interval` between two requests
+pub struct HttpFetcher {
+    base_url: String,
+    session: String,
+    min_interval: Duration,
+    last_request: Mutex<Option<Instant>>,
+    agent: ureq::Agent,
+}
+
+impl HttpFetcher {
+    pub const DEFAULT_MIN_INTERVAL: Duration = Duration::from_secs(5);
+
+    pub fn new(base_url: &str, session: &str) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.trim().to_string(),
+            min_interval: Self::DEFAULT_MIN_INTERVAL,
+            last_request: Mutex::new(None),
+            agent: ureq::AgentBuilder::new()
+                .timeout(Duration::from_secs(30))
+                .user_agent(concat!(
+                    env!("CARGO_PKG_NAME"),
+                    "/",
+                    env!("CARGO_PKG_VERSION")
+                ))
+                .build(),
+        }
+    }
+
+    pub fn with_min_interval(mut self, min_interval: Duration) -> Self {
+        self.min_interval = min_interval;
+        self
+    }
+
+    /// Blocks until `min_interval` has passed since the previous request
+    fn throttle(&self) {
+        let mut last_request = self.last_request.lock().expect("Throttle lock poisoned");
+        if let Some(last) = *last_request {
+            let elapsed = last.elapsed();
+            if elapsed < self.min_interval {
+                let wait = self.min_interval - elapsed;
+                debug!("Waiting {:?} before the next request", wait);
+                thread::sleep(wait);
+            }
+        }
+        *last_request = Some(Instant::now());
+    }
+}
+
+impl InputFetcher for HttpFetcher {
+    fn fetch(&self, year: u16, day: u8) -> Result<String, InputError> {
+        self.throttle();
+
+        let url = format!("{}/{}/day/{}/input", self.base_url, year, day);
+        info!("Fetching {}", url);
+        let response = self
+            .agent
+            .get(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .call();
+
+        match response {
+            Ok(response) => Ok(response.into_string()?),
+            Err(ureq::Error::Status(400 | 401 | 403, _)) => Err(InputError::Unauthorized),
+            Err(ureq::Error::Status(404, _)) => Err(InputError::NotAvailable { year, day }),
+            Err(ureq::Error::Status(429, _)) => Err(InputError::RateLimited),
+            Err(ureq::Error::Status(status, _)) => Err(InputError::Http(status)),
+            Err(ureq::Error::Transport(e)) => Err(InputError::Transport(e.to_string())),
+        }
+    }
+}
+
+/// Inputs cached on disk under `root`, with an optional fetcher for the missing ones
+pub struct InputCache {
+    root: PathBuf,
+    fetcher: Option<Box<dyn InputFetcher>>,
+}
+
+impl InputCache {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self {
+            root: root.into(),
+            fetcher: None,
+        }
+    }
+
+    pub fn with_fetcher(mut self, fetcher: Box<dyn InputFetcher>) -> Self {
+        self.fetcher = Some(fetcher);
+        self
+    }
+
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    pub fn path(&self, year: u16, day: u8) -> PathBuf {
+        self.root.join(year.to_string()).join(format!("day{}.txt", day))
+    }
+
+    /// Returns the cached input, fetching and caching it first if needed
+    pub fn resolve(&self, year: u16, day: u8) -> Result<String, InputError> {
+        let path = self.path(year, day);
+        match fs::read_to_string(&path) {
+            Ok(input) => return Ok(input),
+            Err(e) if e.kind() != io::ErrorKind::NotFound => return Err(e.into()),
+            Err(_) => (),
+        }
+
+        let Some(fetcher) = &self.fetcher else {
+            return Err(InputError::NotCached { year, day });
+        };
+        let input = fetcher.fetch(year, day)?;
+
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&path, &input)?;
+        info!("Cached input for day {} of {} in {:?}", day, year, path);
+        Ok(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_server;
+    use std::cell::Cell;
+    use std::rc::Rc;
+    use tempfile::TempDir;
+
+    struct CountingFetcher {
+        calls: Rc<Cell<usize>>,
+    }
+
+    impl InputFetcher for CountingFetcher {
+        fn fetch(&self, _year: u16, _day: u8) -> Result<String, InputError> {
+            self.calls.set(self.calls.get() + 1);
+            Ok("fetched".to_string())
+        }
+    }
+
+    #[test]
+    fn test_resolve_prefers_cache() {
+        let dir = TempDir::new().unwrap();
+        fs::create_dir_all(dir.path().join("2024")).unwrap();
+        fs::write(dir.path().join("2024/day3.txt"), "cached").unwrap();
+
+        let calls = Rc::new(Cell::new(0));
+        let fetcher = CountingFetcher { calls: calls.clone() };
+        let cache = InputCache::new(dir.path()).with_fetcher(Box::new(fetcher));
+
+        assert_eq!(cache.resolve(2024, 3).unwrap(), "cached");
+        assert_eq!(calls.get(), 0);
+        assert_eq!(cache.resolve(2024, 4).unwrap(), "fetched");
+        assert_eq!(calls.get(), 1);
+    }
+
+    #[test]
+    fn test_resolve_without_fetcher() {
+        let dir = TempDir::new().unwrap();
+        let cache = InputCache::new(dir.path());
+
+        assert!(matches!(
+            cache.resolve(2024, 1),
+            Err(InputError::NotCached { year: 2024, day: 1 })
+        ));
+    }
+
+    #[test]
+    fn test_http_fetch_is_cached() {
+        let (base_url, server) = test_server::serve(vec![(200, "1 2\n3 4\n")]);
+        let dir = TempDir::new().unwrap();
+        let cache = InputCache::new(dir.path())
+            .with_fetcher(Box::new(HttpFetcher::new(&base_url, "secret\n")));
+
+        assert_eq!(cache.resolve(2024, 1).unwrap(), "1 2\n3 4\n");
+        // The test server only answers once, so this must come from the cache
+        assert_eq!(cache.resolve(2024, 1).unwrap(), "1 2\n3 4\n");
+        assert_eq!(fs::read_to_string(cache.path(2024, 1)).unwrap(), "1 2\n3 4\n");
+
+        let requests = server.join().unwrap();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].method, "GET");
+        assert_eq!(requests[0].url, "/2024/day/1/input");
+        assert_eq!(requests[0].cookie.as_deref(), Some("session=secret"));
+    }
+
+    #[test]
+    fn test_http_errors_are_not_cached() {
+        let (base_url, server) = test_server::serve(vec![
+            (400, "Puzzle inputs differ by user.  Please log in to get your puzzle input."),
+            (404, "Please don't repeatedly request this endpoint before it unlocks!"),
+            (429, ""),
+            (500, ""),
+        ]);
+        let dir = TempDir::new().unwrap();
+        let fetcher = HttpFetcher::new(&base_url, "secret").with_min_interval(Duration::ZERO);
+        let cache = InputCache::new(dir.path()).with_fetcher(Box::new(fetcher));
+
+        assert!(matches!(cache.resolve(2024, 1), Err(InputError::Unauthorized)));
+        assert!(matches!(
+            cache.resolve(2024, 25),
+            Err(InputError::NotAvailable { year: 2024, day: 25 })
+        ));
+        assert!(matches!(cache.resolve(2024, 2), Err(InputError::RateLimited)));
+        assert!(matches!(cache.resolve(2024, 3), Err(InputError::Http(500))));
+        assert!(!cache.path(2024, 1).exists());
+
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn test_http_fetch_is_throttled() {
+        let (base_url, server) = test_server::serve(vec![(200, "a"), (200, "b")]);
+        let fetcher =
+            HttpFetcher::new(&base_url, "secret").with_min_interval(Duration::from_millis(300));
+
+        let start = Instant::now();
+        assert_eq!(fetcher.fetch(2024, 1).unwrap(), "a");
+        assert_eq!(fetcher.fetch(2024, 2).unwrap(), "b");
+        assert!(start.elapsed() >= Duration::from_millis(300));
+
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn test_http_fetch_unreachable() {
+        // Nothing listens on the discard port of localhost
+        let fetcher = HttpFetcher::new("http://127.0.0.1:9", "secret");
+        assert!(matches!(fetcher.fetch(2024, 1), Err(InputError::Transport(_))));
+    }
+}
@@ -3,11 +3,32 @@ extern crate aoc_runner;
 #[macro_use]
 extern crate aoc_runner_derive;
 
-mod day1;
-mod day2;
-mod day3;
-mod day4;
-mod day5;
-mod day6;
-
-aoc_lib! { year = 2024 }
\ No newline at end of file
+pub mod y2024;
+
+pub mod answer;
+pub mod examples;
+pub mod fixtures;
+pub mod grid;
+pub mod inputs;
+pub mod parse;
+pub mod profile;
+pub mod scaffold;
+pub mod solver;
+pub mod submit;
+pub mod synth;
+
+#[cfg(test)]
+mod test_server;
+
+use solver::Registry;
+
+/// Registry holding every implemented day of every year
+pub fn registry() -> Registry {
+    let mut registry = Registry::new();
+    y2024::register(&mut registry);
+    registry
+}
+
+// cargo-aoc only runs a single year, so only the 2024 days carry `#[aoc]` attributes. Every year,
+// this one included, is run through `registry()`.
+aoc_lib! { year = 2024 }
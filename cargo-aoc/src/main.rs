@@ -1,6 +0,0 @@
-extern crate cargo_aoc;
-
-use aoc_runner_derive::aoc_main;
-
-aoc_main! { lib= aoc24}
-
@@ -0,0 +1,254 @@
+//! Allocation free parsing of puzzle inputs, working on bytes instead of splitting into
+//! intermediate strings.
+//!
+//! A [`Parser`] is a cursor over the input. Its methods either consume what was asked for and
+//! return it, or leave the cursor where it was and return `None`/`false`, so alternatives can be
+//! tried one after the other.
+
+use std::ops::Neg;
+
+/// Integer types that can be built one decimal digit at a time
+pub trait Unsigned: Copy {
+    const ZERO: Self;
+
+    fn push_digit(self, digit: u8) -> Self;
+}
+
+/// Integer types that can also be negated
+pub trait Signed: Unsigned + Neg<Output = Self> {}
+
+macro_rules! impl_unsigned {
+    ($($t:ty),+) => {
+        $(
+            impl Unsigned for $t {
+                const ZERO: Self = 0;
+
+                #[inline]
+                fn push_digit(self, digit: u8) -> Self {
+                    self * 10 + digit as $t
+                }
+            }
+        )+
+    };
+}
+
+macro_rules! impl_signed {
+    ($($t:ty),+) => {
+        impl_unsigned!($($t),+);
+        $(impl Signed for $t {})+
+    };
+}
+
+impl_unsigned!(u8, u16, u32, u64, u128, usize);
+impl_signed!(i8, i16, i32, i64, i128, isize);
+
+#[derive(Debug, Clone)]
+pub struct Parser<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl<'a> Parser<'a> {
+    pub fn new(input: &'a str) -> Self {
+        Self {
+            bytes: input.as_bytes(),
+            position: 0,
+        }
+    }
+
+    /// True once the whole input has been consumed
+    pub fn is_empty(&self) -> bool {
+        self.position >= self.bytes.len()
+    }
+
+    pub fn peek(&self) -> Option<u8> {
+        self.bytes.get(self.position).copied()
+    }
+
+    /// Moves forward by `count` bytes, stopping at the end of the input
+    pub fn advance(&mut self, count: usize) {
+        self.position = (self.position + count).min(self.bytes.len());
+    }
+
+    /// Consumes `byte` if it is next
+    pub fn skip(&mut self, byte: u8) -> bool {
+        if self.peek() == Some(byte) {
+            self.position += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Consumes `literal` if the input continues with it
+    pub fn literal(&mut self, literal: &[u8]) -> bool {
+        if self.bytes[self.position..].starts_with(literal) {
+            self.position += literal.len();
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Consumes bytes while `predicate` holds, returning how many were consumed
+    pub fn skip_while(&mut self, predicate: impl Fn(u8) -> bool) -> usize {
+        let start = self.position;
+        while self.peek().is_some_and(&predicate) {
+            self.position += 1;
+        }
+        self.position - start
+    }
+
+    /// Consumes spaces and tabs, but not line breaks
+    pub fn skip_spaces(&mut self) -> usize {
+        self.skip_while(|b| b == b' ' || b == b'\t')
+    }
+
+    /// Consumes everything up to the next digit
+    pub fn skip_to_digit(&mut self) {
+        self.skip_while(|b| !b.is_ascii_digit());
+    }
+
+    /// True at a line break or at the end of the input
+    pub fn at_line_end(&self) -> bool {
+        matches!(self.peek(), None | Some(b'\n' | b'\r'))
+    }
+
+    /// Consumes a `\n` or `\r\n` line break
+    pub fn newline(&mut self) -> bool {
+        let start = self.position;
+        self.skip(b'\r');
+        if self.skip(b'\n') {
+            true
+        } else {
+            self.position = start;
+            false
+        }
+    }
+
+    /// Consumes the rest of the current line and its line break
+    pub fn skip_line(&mut self) {
+        self.skip_while(|b| b != b'\n');
+        self.skip(b'\n');
+    }
+
+    /// Consumes an unsigned decimal number
+    pub fn unsigned<T: Unsigned>(&mut self) -> Option<T> {
+        self.unsigned_digits(usize::MAX)
+    }
+
+    /// Consumes an unsigned decimal number of at most `max_digits` digits. Nothing is consumed
+    /// when the number is longer, and digits past the limit are never read into `T`, so a long
+    /// run of digits cannot overflow it.
+    pub fn unsigned_bounded<T: Unsigned>(&mut self, max_digits: usize) -> Option<T> {
+        let start = self.position;
+        match self.unsigned_digits(max_digits) {
+            Some(value) if self.peek().filter(u8::is_ascii_digit).is_none() => Some(value),
+            _ => {
+                self.position = start;
+                None
+            }
+        }
+    }
+
+    /// Consumes a decimal number with an optional leading `-` or `+`
+    pub fn signed<T: Signed>(&mut self) -> Option<T> {
+        let start = self.position;
+        let negative = self.skip(b'-');
+        if !negative {
+            self.skip(b'+');
+        }
+        match self.unsigned::<T>() {
+            Some(value) if negative => Some(-value),
+            Some(value) => Some(value),
+            None => {
+                self.position = start;
+                None
+            }
+        }
+    }
+
+    /// Consumes up to `max_digits` digits
+    fn unsigned_digits<T: Unsigned>(&mut self, max_digits: usize) -> Option<T> {
+        let mut value = T::ZERO;
+        let mut digits = 0;
+        while digits < max_digits {
+            let Some(digit) = self.peek().filter(u8::is_ascii_digit) else {
+                break;
+            };
+            value = value.push_digit(digit - b'0');
+            self.position += 1;
+            digits += 1;
+        }
+        (digits > 0).then_some(value)
+    }
+}
+
+/// Every unsigned number in `input`, ignoring whatever separates them
+pub fn unsigned_numbers<T: Unsigned>(input: &str) -> impl Iterator<Item = T> + '_ {
+    let mut parser = Parser::new(input);
+    std::iter::from_fn(move || {
+        parser.skip_to_digit();
+        parser.unsigned()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_numbers() {
+        let mut parser = Parser::new("12 -7,+3 x");
+        assert_eq!(parser.unsigned::<u32>(), Some(12));
+        assert_eq!(parser.unsigned::<u32>(), None);
+        assert_eq!(parser.skip_spaces(), 1);
+        assert_eq!(parser.unsigned::<u32>(), None);
+        assert_eq!(parser.signed::<i64>(), Some(-7));
+        assert!(parser.skip(b','));
+        assert_eq!(parser.signed::<i8>(), Some(3));
+        assert!(!parser.skip(b','));
+        parser.skip_spaces();
+        assert_eq!(parser.signed::<i32>(), None);
+        assert_eq!(parser.peek(), Some(b'x'));
+    }
+
+    #[test]
+    fn test_unsigned_bounded() {
+        let mut parser = Parser::new("1234,567");
+        assert_eq!(parser.unsigned_bounded::<u32>(3), None);
+        assert_eq!(parser.unsigned_bounded::<u32>(4), Some(1234));
+        assert!(parser.skip(b','));
+        assert_eq!(parser.unsigned_bounded::<u32>(3), Some(567));
+        assert!(parser.is_empty());
+
+        // Far more digits than fit in a u16, rejected without overflowing
+        let input = "9".repeat(40);
+        let mut parser = Parser::new(&input);
+        assert_eq!(parser.unsigned_bounded::<u16>(3), None);
+        assert_eq!(parser.peek(), Some(b'9'));
+    }
+
+    #[test]
+    fn test_literals_and_lines() {
+        let mut parser = Parser::new("mul(1)\r\n\nend");
+        assert!(!parser.literal(b"mux("));
+        assert!(parser.literal(b"mul("));
+        assert_eq!(parser.unsigned::<u8>(), Some(1));
+        assert!(!parser.at_line_end());
+        assert!(parser.skip(b')'));
+        assert!(parser.at_line_end());
+        assert!(parser.newline());
+        assert!(parser.newline());
+        assert!(!parser.newline());
+        parser.skip_line();
+        assert!(parser.is_empty());
+        assert!(parser.at_line_end());
+    }
+
+    #[test]
+    fn test_unsigned_numbers() {
+        let numbers = unsigned_numbers::<u64>("p=0,4 v=3,-3\n").collect::<Vec<u64>>();
+        assert_eq!(numbers, vec![0, 4, 3, 3]);
+    }
+}
@@ -0,0 +1,261 @@
+//! Repeated timing and heap measurement of every step of a day, for finding the solutions worth
+//! optimising.
+//!
+//! Heap use is only measured when [`CountingAllocator`] is the global allocator of the binary:
+//!
+//! ```ignore
+//! #[global_allocator]
+//! static ALLOCATOR: CountingAllocator = CountingAllocator;
+//! ```
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::fmt::Write;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+use crate::solver::Puzzle;
+
+static CURRENT: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+/// Global allocator forwarding to the system allocator while keeping track of the bytes in use and
+/// their peak
+pub struct CountingAllocator;
+
+impl CountingAllocator {
+    /// Whether this is the global allocator, as nothing else updates the counters
+    pub fn is_installed() -> bool {
+        PEAK.load(Ordering::Relaxed) > 0
+    }
+
+    fn grow(size: usize) {
+        let current = CURRENT.fetch_add(size, Ordering::Relaxed) + size;
+        PEAK.fetch_max(current, Ordering::Relaxed);
+    }
+
+    fn shrink(size: usize) {
+        CURRENT.fetch_sub(size, Ordering::Relaxed);
+    }
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            Self::grow(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            Self::grow(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        Self::shrink(layout.size());
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            if new_size > layout.size() {
+                Self::grow(new_size - layout.size());
+            } else {
+                Self::shrink(layout.size() - new_size);
+            }
+        }
+        new_ptr
+    }
+}
+
+/// Runs `f`, returning its result, how long it took and the most heap it had allocated at any
+/// point on top of what was already allocated
+pub fn measure<R>(f: impl FnOnce() -> R) -> (R, Duration, usize) {
+    let baseline = CURRENT.load(Ordering::Relaxed);
+    PEAK.store(baseline, Ordering::Relaxed);
+
+    let start = Instant::now();
+    let result = f();
+    let elapsed = start.elapsed();
+
+    (result, elapsed, PEAK.load(Ordering::Relaxed).saturating_sub(baseline))
+}
+
+/// Timings and peak heap of one step over every run
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Measurement {
+    pub min: Duration,
+    pub median: Duration,
+    pub p95: Duration,
+    pub peak_heap: usize,
+}
+
+impl Measurement {
+    pub fn new(mut times: Vec<Duration>, peak_heap: usize) -> Self {
+        assert!(!times.is_empty(), "Cannot summarise zero runs");
+        times.sort();
+        // Nearest rank percentiles
+        let percentile = |p: usize| times[(times.len() * p).div_ceil(100).max(1) - 1];
+        Self {
+            min: times[0],
+            median: percentile(50),
+            p95: percentile(95),
+            peak_heap,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct DayProfile {
+    pub year: u16,
+    pub day: u8,
+    pub generator: Measurement,
+    /// Measurements of the solved parts, by part number
+    pub parts: Vec<(u8, Measurement)>,
+}
+
+/// Parses `input` and solves both parts `runs` times. Every run starts from a fresh parse, which
+/// is dropped before the next run.
+pub fn profile(puzzle: &dyn Puzzle, input: &str, runs: usize) -> DayProfile {
+    let runs = runs.max(1);
+    let mut generator = (Vec::with_capacity(runs), 0);
+    let mut parts = [1, 2].map(|part| (part, Vec::with_capacity(runs), 0, false));
+
+    for _ in 0..runs {
+        let (parsed, time, heap) = measure(|| puzzle.parse_input(input));
+        generator.0.push(time);
+        generator.1 = generator.1.max(heap);
+
+        for (part, times, peak, solved) in parts.iter_mut() {
+            let (answer, time, heap) = measure(|| puzzle.solve(parsed.as_ref(), *part));
+            *solved = answer.is_some();
+            times.push(time);
+            *peak = (*peak).max(heap);
+        }
+    }
+
+    DayProfile {
+        year: puzzle.year(),
+        day: puzzle.day(),
+        generator: Measurement::new(generator.0, generator.1),
+        parts: parts
+            .into_iter()
+            .filter(|(_, _, _, solved)| *solved)
+            .map(|(part, times, peak, _)| (part, Measurement::new(times, peak)))
+            .collect(),
+    }
+}
+
+/// Markdown table with one row per step of every day. Heap columns read `n/a` unless
+/// [`CountingAllocator`] is installed.
+pub fn markdown_table(profiles: &[DayProfile]) -> String {
+    let heap = |bytes: usize| {
+        if CountingAllocator::is_installed() {
+            format_bytes(bytes)
+        } else {
+            "n/a".to_string()
+        }
+    };
+
+    let mut table = String::from("| Year | Day | Step | Min | Median | p95 | Peak heap |\n");
+    table.push_str("|---:|---:|---|---:|---:|---:|---:|\n");
+    for profile in profiles {
+        let steps = std::iter::once(("Generator".to_string(), &profile.generator)).chain(
+            profile
+                .parts
+                .iter()
+                .map(|(part, measurement)| (format!("Part {}", part), measurement)),
+        );
+        for (step, m) in steps {
+            writeln!(
+                table,
+                "| {} | {} | {} | {:?} | {:?} | {:?} | {} |",
+                profile.year,
+                profile.day,
+                step,
+                m.min,
+                m.median,
+                m.p95,
+                heap(m.peak_heap)
+            )
+            .unwrap();
+        }
+    }
+    table
+}
+
+fn format_bytes(bytes: usize) -> String {
+    const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    match unit {
+        0 => format!("{} B", bytes),
+        _ => format!("{:.1} {}", value, UNITS[unit]),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    #[global_allocator]
+    static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+    /// Measurements reset the shared peak, so tests taking them must not overlap
+    static MEASURING: Mutex<()> = Mutex::new(());
+
+    #[test]
+    fn test_measurement_percentiles() {
+        let times = (1..=20).rev().map(Duration::from_millis).collect();
+        let m = Measurement::new(times, 0);
+        assert_eq!(m.min, Duration::from_millis(1));
+        assert_eq!(m.median, Duration::from_millis(10));
+        assert_eq!(m.p95, Duration::from_millis(19));
+
+        let three = Duration::from_millis(3);
+        let m = Measurement::new(vec![three], 0);
+        assert_eq!((m.min, m.median, m.p95), (three, three, three));
+    }
+
+    #[test]
+    fn test_measure_counts_heap() {
+        let _guard = MEASURING.lock().unwrap();
+        let (length, _, heap) = measure(|| vec![0u8; 1 << 20].len());
+        assert_eq!(length, 1 << 20);
+        // Other tests may allocate at the same time, so this is only a lower bound
+        assert!(heap >= 1 << 20);
+        assert!(CountingAllocator::is_installed());
+    }
+
+    #[test]
+    fn test_profile_table() {
+        let _guard = MEASURING.lock().unwrap();
+        let registry = crate::registry();
+        let puzzle = registry.get(2024, 3).unwrap();
+        let profile = profile(puzzle, "mul(2,4)mul(3,5)", 5);
+
+        assert_eq!(profile.parts.len(), 2);
+        let table = markdown_table(&[profile]);
+        let lines = table.lines().collect::<Vec<&str>>();
+        assert_eq!(lines.len(), 5);
+        assert!(lines[2].starts_with("| 2024 | 3 | Generator |"));
+        assert!(lines[4].starts_with("| 2024 | 3 | Part 2 |"));
+    }
+
+    #[test]
+    fn test_format_bytes() {
+        assert_eq!(format_bytes(512), "512 B");
+        assert_eq!(format_bytes(1536), "1.5 KiB");
+        assert_eq!(format_bytes(3 << 20), "3.0 MiB");
+    }
+}
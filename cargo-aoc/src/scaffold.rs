@@ -0,0 +1,387 @@
+//! Generates the boilerplate for a new day: the `src/y<year>/day<N>.rs` module from a template, its
+//! registration in the year's `mod.rs` and an empty example fixture. The first day of a new year
+//! also creates the year module and registers it in `lib.rs`.
+
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use regex::Regex;
+use tracing::info;
+
+const TEMPLATE: &str = r#"use crate::answer::Answer;
+use crate::solver::Solver;
+
+#[aoc_generator(day{day})]
+pub fn input_generator(input: &str) -> Vec<String> {
+    input.lines().map(str::to_string).collect()
+}
+
+// The day is registered as soon as it is generated, so its parts return `Answer::Unsolved`
+// rather than panicking when every day is run. The registry reports that as no answer, so it is
+// never printed or submitted.
+
+#[aoc(day{day}, part1)]
+pub fn part1(_input: &[String]) -> Answer {
+    Answer::Unsolved
+}
+
+#[aoc(day{day}, part2)]
+pub fn part2(_input: &[String]) -> Answer {
+    Answer::Unsolved
+}
+
+pub struct Day{day};
+
+impl Solver for Day{day} {
+    const YEAR: u16 = {year};
+    const DAY: u8 = {day};
+    type Input = Vec<String>;
+    type Output = Answer;
+
+    fn parse(input: &str) -> Self::Input {
+        input_generator(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Output {
+        part1(input)
+    }
+
+    // Add `part2` returning `Some(part2(input))` once part 2 is solved
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures;
+
+    #[test]
+    #[ignore = "the example answer has not been filled in yet"]
+    fn test_part1() {
+        let input = input_generator(&fixtures::load({year}, "day{day}.txt"));
+        // Replace with the answer given for the example in the puzzle description and remove the
+        // ignore attribute
+        assert_eq!(part1(&input), "");
+    }
+
+    #[test]
+    #[ignore = "the example answer has not been filled in yet"]
+    fn test_part2() {
+        let input = input_generator(&fixtures::load({year}, "day{day}.txt"));
+        // Replace with the answer given for the example in the puzzle description and remove the
+        // ignore attribute
+        assert_eq!(part2(&input), "");
+    }
+}
+"#;
+
+#[derive(Debug)]
+pub enum ScaffoldError {
+    Io(io::Error),
+    /// The day already has a module, which is never overwritten
+    DayExists(u8),
+    /// There is no line of the given kind to insert the new one next to
+    NoInsertionPoint(&'static str),
+}
+
+impl Display for ScaffoldError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ScaffoldError::Io(e) => write!(f, "I/O error: {}", e),
+            ScaffoldError::DayExists(day) => write!(f, "Day {} already exists", day),
+            ScaffoldError::NoInsertionPoint(kind) => {
+                write!(f, "Could not find where to add the {}", kind)
+            }
+        }
+    }
+}
+
+impl Error for ScaffoldError {}
+
+impl From<io::Error> for ScaffoldError {
+    fn from(e: io::Error) -> Self {
+        ScaffoldError::Io(e)
+    }
+}
+
+/// Source of the module for a new day. cargo-aoc only runs a single year, so the `#[aoc]`
+/// attributes are only generated for days of that year.
+pub fn render_day(year: u16, day: u8, aoc_runner: bool) -> String {
+    let source = TEMPLATE
+        .replace("{day}", &day.to_string())
+        .replace("{year}", &year.to_string());
+    if aoc_runner {
+        source
+    } else {
+        source
+            .lines()
+            .filter(|line| !line.starts_with("#[aoc"))
+            .map(|line| format!("{}\n", line))
+            .collect()
+    }
+}
+
+/// Source of the module for a new year, holding only its first day
+fn render_year(year: u16, day: u8) -> String {
+    format!(
+        "//! Advent of Code {year}
+
+pub mod day{day};
+
+use crate::solver::Registry;
+
+/// Adds every implemented day of {year} to `registry`
+pub fn register(registry: &mut Registry) {{
+    registry.register(day{day}::Day{day});
+}}
+"
+    )
+}
+
+/// Adds `pub mod day<N>;` and its registration to the contents of a year's `mod.rs`, keeping both
+/// lists ordered by day
+pub fn register_day(year_rs: &str, day: u8) -> Result<String, ScaffoldError> {
+    let module = Regex::new(r"^pub mod day(\d+);$").unwrap();
+    if year_rs
+        .lines()
+        .any(|line| module.captures(line).is_some_and(|c| c[1] == day.to_string()))
+    {
+        return Err(ScaffoldError::DayExists(day));
+    }
+
+    register(
+        year_rs,
+        day.into(),
+        (&module, format!("pub mod day{};", day)),
+        (
+            &Regex::new(r"^\s*registry\.register\(day(\d+)::Day\d+\);$").unwrap(),
+            format!("registry.register(day{}::Day{});", day, day),
+        ),
+    )
+}
+
+/// Adds `pub mod y<year>;` and its registration to the contents of `lib.rs`
+fn register_year(lib_rs: &str, year: u16) -> Result<String, ScaffoldError> {
+    register(
+        lib_rs,
+        year,
+        (
+            &Regex::new(r"^pub mod y(\d+);$").unwrap(),
+            format!("pub mod y{};", year),
+        ),
+        (
+            &Regex::new(r"^\s*y(\d+)::register\(&mut registry\);$").unwrap(),
+            format!("y{}::register(&mut registry);", year),
+        ),
+    )
+}
+
+/// Inserts a module declaration and a registration for `key`, each next to the existing lines
+/// matching its pattern
+fn register(
+    contents: &str,
+    key: u16,
+    (module, module_line): (&Regex, String),
+    (registration, registration_line): (&Regex, String),
+) -> Result<String, ScaffoldError> {
+    let mut lines = contents.lines().map(str::to_string).collect::<Vec<String>>();
+
+    let (index, _) =
+        insertion_index(&lines, module, key).ok_or(ScaffoldError::NoInsertionPoint("module"))?;
+    lines.insert(index, module_line);
+
+    let (index, neighbour) = insertion_index(&lines, registration, key)
+        .ok_or(ScaffoldError::NoInsertionPoint("registration"))?;
+    let indent = lines[neighbour]
+        .chars()
+        .take_while(|c| c.is_whitespace())
+        .collect::<String>();
+    lines.insert(index, format!("{}{}", indent, registration_line));
+
+    let mut contents = lines.join("\n");
+    contents.push('\n');
+    Ok(contents)
+}
+
+/// Index to insert a line for `key` at, before the first matching line for a later key or
+/// otherwise after the last matching line, along with the index of that matching line
+fn insertion_index(lines: &[String], pattern: &Regex, key: u16) -> Option<(usize, usize)> {
+    let mut index = None;
+    for (i, line) in lines.iter().enumerate() {
+        if let Some(captures) = pattern.captures(line) {
+            if captures[1].parse::<u16>().is_ok_and(|k| k > key) {
+                return Some((i, i));
+            }
+            index = Some((i + 1, i));
+        }
+    }
+    index
+}
+
+/// Year run by cargo-aoc, read from the `aoc_lib!` invocation in `lib.rs`
+fn aoc_runner_year(lib_rs: &str) -> Option<u16> {
+    let aoc_lib = Regex::new(r"aoc_lib!\s*\{\s*year\s*=\s*(\d+)\s*\}").unwrap();
+    aoc_lib.captures(lib_rs)?[1].parse().ok()
+}
+
+/// Creates the module, registration and example fixture for a day in the crate at `root`,
+/// creating the year module first if needed, and returns the files written. An existing fixture is
+/// kept, as it may have been extracted already.
+pub fn new_day(root: &Path, year: u16, day: u8) -> Result<Vec<PathBuf>, ScaffoldError> {
+    let year_dir = root.join("src").join(format!("y{}", year));
+    let module = year_dir.join(format!("day{}.rs", day));
+    if module.exists() {
+        return Err(ScaffoldError::DayExists(day));
+    }
+
+    let lib = root.join("src/lib.rs");
+    let lib_rs = fs::read_to_string(&lib)?;
+    let year_mod = year_dir.join("mod.rs");
+    let mut written = Vec::new();
+
+    let year_rs = if year_mod.exists() {
+        register_day(&fs::read_to_string(&year_mod)?, day)?
+    } else {
+        fs::create_dir_all(&year_dir)?;
+        fs::write(&lib, register_year(&lib_rs, year)?)?;
+        written.push(lib.clone());
+        render_year(year, day)
+    };
+
+    let aoc_runner = aoc_runner_year(&lib_rs) == Some(year);
+    fs::write(&module, render_day(year, day, aoc_runner))?;
+    fs::write(&year_mod, year_rs)?;
+    written.push(module);
+    written.push(year_mod);
+
+    let fixture = root
+        .join("test_input")
+        .join(year.to_string())
+        .join(format!("day{}.txt", day));
+    if !fixture.exists() {
+        fs::create_dir_all(fixture.parent().unwrap())?;
+        fs::write(&fixture, "")?;
+        written.push(fixture);
+    }
+
+    info!("Scaffolded day {} of {}", day, year);
+    Ok(written)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    const YEAR_RS: &str = "//! Advent of Code 2024
+
+pub mod day1;
+pub mod day3;
+
+pub mod synth;
+
+pub fn register(registry: &mut Registry) {
+    registry.register(day1::Day1);
+    registry.register(day3::Day3);
+}
+";
+
+    const LIB_RS: &str = "pub mod y2024;
+
+pub mod answer;
+
+pub fn registry() -> Registry {
+    let mut registry = Registry::new();
+    y2024::register(&mut registry);
+    registry
+}
+
+aoc_lib! { year = 2024 }
+";
+
+    #[test]
+    fn test_register_day() {
+        let year_rs = register_day(YEAR_RS, 2).unwrap();
+        assert!(year_rs.contains("pub mod day1;\npub mod day2;\npub mod day3;\n"));
+        assert!(year_rs.contains(
+            "    registry.register(day1::Day1);\n    registry.register(day2::Day2);\n    registry.register(day3::Day3);\n"
+        ));
+
+        let year_rs = register_day(YEAR_RS, 12).unwrap();
+        assert!(year_rs.contains("pub mod day3;\npub mod day12;\n"));
+        assert!(year_rs.contains("registry.register(day3::Day3);\n    registry.register(day12::Day12);\n"));
+
+        assert!(matches!(register_day(YEAR_RS, 3), Err(ScaffoldError::DayExists(3))));
+    }
+
+    #[test]
+    fn test_render_day() {
+        let source = render_day(2024, 7, true);
+        assert!(source.contains("#[aoc_generator(day7)]"));
+        assert!(source.contains("#[aoc(day7, part2)]"));
+        assert!(source.contains("pub struct Day7;"));
+        assert!(source.contains("fixtures::load(2024, \"day7.txt\")"));
+        assert!(!source.contains("{day}") && !source.contains("{year}"));
+        // Registered right away, so nothing may panic when every day is run
+        assert!(!source.contains("todo!"));
+        assert_eq!(source.matches("    Answer::Unsolved\n").count(), 2);
+        // Part 2 is left to the trait's default until it is wired up
+        assert!(!source.contains("fn part2(input: &Self::Input)"));
+        assert_eq!(source.matches("#[ignore").count(), 2);
+
+        let source = render_day(2025, 7, false);
+        assert!(!source.contains("#[aoc"));
+        assert!(source.contains("const YEAR: u16 = 2025;"));
+    }
+
+    fn crate_root() -> TempDir {
+        let dir = TempDir::new().unwrap();
+        fs::create_dir_all(dir.path().join("src/y2024")).unwrap();
+        fs::write(dir.path().join("src/lib.rs"), LIB_RS).unwrap();
+        fs::write(dir.path().join("src/y2024/mod.rs"), YEAR_RS).unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_new_day() {
+        let dir = crate_root();
+
+        let written = new_day(dir.path(), 2024, 7).unwrap();
+        assert_eq!(written.len(), 3);
+        assert!(fs::read_to_string(dir.path().join("src/y2024/day7.rs"))
+            .unwrap()
+            .contains("const DAY: u8 = 7;"));
+        assert!(fs::read_to_string(dir.path().join("src/y2024/mod.rs"))
+            .unwrap()
+            .contains("registry.register(day7::Day7);"));
+        assert!(dir.path().join("test_input/2024/day7.txt").exists());
+        assert_eq!(fs::read_to_string(dir.path().join("src/lib.rs")).unwrap(), LIB_RS);
+
+        fs::write(dir.path().join("src/y2024/day7.rs"), "solved").unwrap();
+        assert!(matches!(new_day(dir.path(), 2024, 7), Err(ScaffoldError::DayExists(7))));
+        assert_eq!(fs::read_to_string(dir.path().join("src/y2024/day7.rs")).unwrap(), "solved");
+    }
+
+    #[test]
+    fn test_new_year() {
+        let dir = crate_root();
+
+        let written = new_day(dir.path(), 2025, 1).unwrap();
+        assert_eq!(written.len(), 4);
+
+        let lib_rs = fs::read_to_string(dir.path().join("src/lib.rs")).unwrap();
+        assert!(lib_rs.starts_with("pub mod y2024;\npub mod y2025;\n"));
+        assert!(lib_rs.contains(
+            "    y2024::register(&mut registry);\n    y2025::register(&mut registry);\n"
+        ));
+        assert!(fs::read_to_string(dir.path().join("src/y2025/mod.rs"))
+            .unwrap()
+            .contains("pub mod day1;"));
+        assert!(!fs::read_to_string(dir.path().join("src/y2025/day1.rs"))
+            .unwrap()
+            .contains("#[aoc"));
+        assert!(dir.path().join("test_input/2025/day1.txt").exists());
+    }
+}
@@ -0,0 +1,239 @@
+//! Solutions as plain Rust types, independent of the `aoc-runner` macros.
+//!
+//! Every day implements [`Solver`] on a unit struct and is added to the [`Registry`] returned by
+//! [`crate::registry`], which allows other crates to enumerate, run, benchmark and test the
+//! solutions.
+
+use std::any::Any;
+use std::collections::BTreeMap;
+
+use crate::answer::Answer;
+
+/// A single day of a single year
+pub trait Solver {
+    const YEAR: u16;
+    const DAY: u8;
+
+    /// Parsed puzzle input, shared by both parts
+    type Input;
+    type Output: Into<Answer>;
+
+    fn parse(input: &str) -> Self::Input;
+
+    fn part1(input: &Self::Input) -> Self::Output;
+
+    /// `None` while part 2 has not been solved
+    fn part2(_input: &Self::Input) -> Option<Self::Output> {
+        None
+    }
+}
+
+/// Object safe view of a [`Solver`], so solvers with different input and output types can be
+/// stored together
+pub trait Puzzle {
+    fn year(&self) -> u16;
+
+    fn day(&self) -> u8;
+
+    /// Parses the raw input, the result is only meaningful to [`Puzzle::solve`] of this puzzle
+    fn parse_input(&self, input: &str) -> Box<dyn Any>;
+
+    /// Solves `part` on input returned by [`Puzzle::parse_input`], `None` if the part is not
+    /// solved, including when it returns [`Answer::Unsolved`]
+    fn solve(&self, input: &dyn Any, part: u8) -> Option<Answer>;
+
+    /// Parses the raw input and solves `part`
+    fn run(&self, input: &str, part: u8) -> Option<Answer> {
+        self.solve(self.parse_input(input).as_ref(), part)
+    }
+}
+
+impl<S> Puzzle for S
+where
+    S: Solver,
+    S::Input: 'static,
+{
+    fn year(&self) -> u16 {
+        S::YEAR
+    }
+
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn parse_input(&self, input: &str) -> Box<dyn Any> {
+        Box::new(S::parse(input))
+    }
+
+    fn solve(&self, input: &dyn Any, part: u8) -> Option<Answer> {
+        let input = input
+            .downcast_ref::<S::Input>()
+            .unwrap_or_else(|| panic!("Input was not parsed by day {} of {}", S::DAY, S::YEAR));
+        let answer = match part {
+            1 => Some(S::part1(input).into()),
+            2 => S::part2(input).map(Into::into),
+            _ => None,
+        };
+        answer.filter(|answer| !answer.is_unsolved())
+    }
+}
+
+/// Solvers keyed by (year, day)
+#[derive(Default)]
+pub struct Registry {
+    puzzles: BTreeMap<(u16, u8), Box<dyn Puzzle>>,
+}
+
+impl Registry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a solver, panicking if its year and day are already registered
+    pub fn register<S>(&mut self, solver: S)
+    where
+        S: Solver + 'static,
+        S::Input: 'static,
+    {
+        let key = (S::YEAR, S::DAY);
+        if self.puzzles.insert(key, Box::new(solver)).is_some() {
+            panic!("Day {} of {} is registered twice", S::DAY, S::YEAR);
+        }
+    }
+
+    pub fn get(&self, year: u16, day: u8) -> Option<&dyn Puzzle> {
+        self.puzzles.get(&(year, day)).map(|p| p.as_ref())
+    }
+
+    /// All puzzles ordered by year then day
+    pub fn iter(&self) -> impl Iterator<Item = &dyn Puzzle> {
+        self.puzzles.values().map(|p| p.as_ref())
+    }
+
+    /// Puzzles of `year`, ordered by day
+    pub fn year(&self, year: u16) -> impl Iterator<Item = &dyn Puzzle> {
+        self.puzzles
+            .range((year, 0)..=(year, u8::MAX))
+            .map(|(_, p)| p.as_ref())
+    }
+
+    pub fn len(&self) -> usize {
+        self.puzzles.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.puzzles.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Sum;
+
+    impl Solver for Sum {
+        const YEAR: u16 = 2015;
+        const DAY: u8 = 3;
+        type Input = Vec<u32>;
+        type Output = u32;
+
+        fn parse(input: &str) -> Self::Input {
+            input.split(',').map(|n| n.parse().unwrap()).collect()
+        }
+
+        fn part1(input: &Self::Input) -> Self::Output {
+            input.iter().sum()
+        }
+    }
+
+    struct Max;
+
+    impl Solver for Max {
+        const YEAR: u16 = 2015;
+        const DAY: u8 = 1;
+        type Input = Vec<u32>;
+        type Output = u32;
+
+        fn parse(input: &str) -> Self::Input {
+            Sum::parse(input)
+        }
+
+        fn part1(input: &Self::Input) -> Self::Output {
+            *input.iter().max().unwrap()
+        }
+
+        fn part2(input: &Self::Input) -> Option<Self::Output> {
+            input.iter().min().copied()
+        }
+    }
+
+    #[test]
+    fn test_registry_runs_puzzles() {
+        let mut registry = Registry::new();
+        registry.register(Sum);
+        registry.register(Max);
+
+        assert_eq!(registry.len(), 2);
+        assert_eq!(
+            registry.iter().map(|p| (p.year(), p.day())).collect::<Vec<_>>(),
+            vec![(2015, 1), (2015, 3)]
+        );
+        assert_eq!(registry.year(2016).count(), 0);
+
+        let sum = registry.get(2015, 3).unwrap();
+        assert_eq!(sum.run("1,2,3", 1), Some(Answer::from(6u32)));
+        assert_eq!(sum.run("1,2,3", 2), None);
+
+        let max = registry.get(2015, 1).unwrap();
+        let parsed = max.parse_input("4,9,2");
+        assert_eq!(max.solve(parsed.as_ref(), 1), Some(Answer::from(9u32)));
+        assert_eq!(max.solve(parsed.as_ref(), 2), Some(Answer::from(2u32)));
+        assert_eq!(max.solve(parsed.as_ref(), 3), None);
+    }
+
+    struct Stub;
+
+    impl Solver for Stub {
+        const YEAR: u16 = 2015;
+        const DAY: u8 = 2;
+        type Input = ();
+        type Output = Answer;
+
+        fn parse(_input: &str) -> Self::Input {}
+
+        fn part1(_input: &Self::Input) -> Self::Output {
+            Answer::Unsolved
+        }
+    }
+
+    #[test]
+    fn test_unsolved_part_has_no_answer() {
+        assert_eq!(Stub.run("", 1), None);
+        assert_eq!(Stub.run("", 2), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "Day 3 of 2015 is registered twice")]
+    fn test_registry_rejects_duplicates() {
+        let mut registry = Registry::new();
+        registry.register(Sum);
+        registry.register(Sum);
+    }
+
+    #[test]
+    fn test_crate_registry() {
+        let registry = crate::registry();
+        // Days known to be solved, which must stay registered as later days are added. Their
+        // answers are checked against the example fixtures in `fixtures`.
+        for day in 1..=16 {
+            let puzzle = registry.get(2024, day).unwrap_or_else(|| panic!("Day {} is not registered", day));
+            assert_eq!((puzzle.year(), puzzle.day()), (2024, day));
+        }
+        assert!(registry.get(2024, 0).is_none());
+        assert_eq!(
+            registry.get(2024, 3).unwrap().run("mul(2,4)mul(3,5)", 1),
+            Some(Answer::from(23u32))
+        );
+    }
+}
@@ -0,0 +1,464 @@
+//! Submits answers to the puzzle website and interprets the reply.
+//!
+//! Rejected answers and cooldowns are remembered on disk per (year, day), so the same wrong
+//! answer is never sent twice and no request is made while the site asks us to wait.
+
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use regex::Regex;
+use tracing::info;
+
+use crate::answer::Answer;
+
+/// The site's reply to a submitted answer
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without a hint about the direction
+    Incorrect,
+    /// The answer was not checked because the previous submission was too recent
+    Wait(Duration),
+    /// The part has already been solved, or is not unlocked yet
+    WrongLevel,
+    /// A reply we do not recognise, holding the text of the page
+    Unknown(String),
+}
+
+impl Verdict {
+    fn is_rejection(&self) -> bool {
+        matches!(self, Verdict::TooHigh | Verdict::TooLow | Verdict::Incorrect)
+    }
+
+    fn as_str(&self) -> &str {
+        match self {
+            Verdict::Correct => "correct",
+            Verdict::TooHigh => "too_high",
+            Verdict::TooLow => "too_low",
+            Verdict::Incorrect => "incorrect",
+            Verdict::Wait(_) => "wait",
+            Verdict::WrongLevel => "wrong_level",
+            Verdict::Unknown(_) => "unknown",
+        }
+    }
+
+    fn from_str(s: &str) -> Option<Self> {
+        match s {
+            "too_high" => Some(Verdict::TooHigh),
+            "too_low" => Some(Verdict::TooLow),
+            "incorrect" => Some(Verdict::Incorrect),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "That's the right answer"),
+            Verdict::TooHigh => write!(f, "That's not the right answer, it is too high"),
+            Verdict::TooLow => write!(f, "That's not the right answer, it is too low"),
+            Verdict::Incorrect => write!(f, "That's not the right answer"),
+            Verdict::Wait(d) => write!(f, "Answered too recently, wait {:?}", d),
+            Verdict::WrongLevel => write!(f, "Not solving the right level"),
+            Verdict::Unknown(text) => write!(f, "Unrecognised reply: {}", text),
+        }
+    }
+}
+
+/// Interprets the HTML page returned after posting an answer. The returned duration is the
+/// cooldown the site imposes before the next submission, if it mentions one.
+pub fn parse_response(html: &str) -> (Verdict, Option<Duration>) {
+    let article = Regex::new(r"(?s)<article[^>]*>(.*?)</article>")
+        .unwrap()
+        .captures(html)
+        .map_or(html, |c| c.get(1).unwrap().as_str());
+    let text = Regex::new(r"<[^>]+>").unwrap().replace_all(article, "");
+    let text = text.split_whitespace().collect::<Vec<&str>>().join(" ");
+
+    if text.contains("That's the right answer") {
+        return (Verdict::Correct, None);
+    }
+    if text.contains("You don't seem to be solving the right level") {
+        return (Verdict::WrongLevel, None);
+    }
+    if text.contains("You gave an answer too recently") {
+        let left = Regex::new(r"(?:(\d+)h ?)?(?:(\d+)m ?)?(?:(\d+)s)? left to wait")
+            .unwrap()
+            .captures(&text)
+            .map(|c| {
+                let unit = |i: usize| c.get(i).map_or(0, |m| m.as_str().parse::<u64>().unwrap());
+                Duration::from_secs(unit(1) * 3600 + unit(2) * 60 + unit(3))
+            })
+            .filter(|d| !d.is_zero())
+            .unwrap_or(Duration::from_secs(60));
+        return (Verdict::Wait(left), Some(left));
+    }
+    if text.contains("That's not the right answer") {
+        let verdict = if text.contains("your answer is too high") {
+            Verdict::TooHigh
+        } else if text.contains("your answer is too low") {
+            Verdict::TooLow
+        } else {
+            Verdict::Incorrect
+        };
+        let cooldown = Regex::new(r"(?i)wait (one|\d+) minutes?")
+            .unwrap()
+            .captures(&text)
+            .map(|c| match c.get(1).unwrap().as_str() {
+                "one" => 1,
+                n => n.parse::<u64>().unwrap(),
+            })
+            .map(|minutes| Duration::from_secs(minutes * 60));
+        return (verdict, cooldown);
+    }
+    (Verdict::Unknown(text), None)
+}
+
+#[derive(Debug)]
+pub enum SubmitError {
+    Io(io::Error),
+    /// This exact answer was rejected before and was not sent again
+    PreviouslyRejected(Verdict),
+    /// The site asked us to wait, nothing was sent
+    CoolingDown(Duration),
+    /// The session token was missing, expired or rejected
+    Unauthorized,
+    Http(u16),
+    Transport(String),
+}
+
+impl Display for SubmitError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SubmitError::Io(e) => write!(f, "I/O error: {}", e),
+            SubmitError::PreviouslyRejected(verdict) => {
+                write!(f, "Answer was already rejected: {}", verdict)
+            }
+            SubmitError::CoolingDown(d) => write!(f, "Still cooling down for {:?}", d),
+            SubmitError::Unauthorized => write!(f, "Session token was rejected"),
+            SubmitError::Http(status) => write!(f, "Unexpected HTTP status {}", status),
+            SubmitError::Transport(e) => write!(f, "Failed to reach server: {}", e),
+        }
+    }
+}
+
+impl Error for SubmitError {}
+
+impl From<io::Error> for SubmitError {
+    fn from(e: io::Error) -> Self {
+        SubmitError::Io(e)
+    }
+}
+
+/// What we remember about the submissions for one day
+#[derive(Debug, Default)]
+struct History {
+    /// (part, answer, verdict)
+    rejected: Vec<(u8, String, Verdict)>,
+    /// Unix time in seconds before which nothing may be submitted
+    cooldown_until: u64,
+}
+
+impl History {
+    fn load(path: &Path) -> Result<Self, io::Error> {
+        let contents = match fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Self::default()),
+            Err(e) => return Err(e),
+        };
+
+        let mut history = Self::default();
+        for line in contents.lines() {
+            let fields = line.splitn(4, '\t').collect::<Vec<&str>>();
+            match fields.as_slice() {
+                ["cooldown", until] => history.cooldown_until = until.parse().unwrap_or(0),
+                ["rejected", part, verdict, answer] => {
+                    if let (Ok(part), Some(verdict)) = (part.parse(), Verdict::from_str(verdict)) {
+                        history.rejected.push((part, unescape(answer), verdict));
+                    }
+                }
+                _ => (),
+            }
+        }
+        Ok(history)
+    }
+
+    fn save(&self, path: &Path) -> Result<(), io::Error> {
+        let mut contents = format!("cooldown\t{}\n", self.cooldown_until);
+        for (part, answer, verdict) in &self.rejected {
+            contents.push_str(&format!("rejected\t{}\t{}\t{}\n", part, verdict.as_str(), escape(answer)));
+        }
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, contents)
+    }
+}
+
+/// Escapes the characters that would split an answer across history fields or lines, so
+/// multi-line answers such as grids are stored on one line
+fn escape(answer: &str) -> String {
+    let mut escaped = String::with_capacity(answer.len());
+    for c in answer.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            '\t' => escaped.push_str("\\t"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Reverses [`escape`]
+fn unescape(field: &str) -> String {
+    let mut answer = String::with_capacity(field.len());
+    let mut chars = field.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            answer.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('t') => answer.push('\t'),
+            Some('n') => answer.push('\n'),
+            Some('r') => answer.push('\r'),
+            Some(other) => answer.push(other),
+            None => answer.push('\\'),
+        }
+    }
+    answer
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("System clock is before 1970")
+        .as_secs()
+}
+
+/// Posts answers to `<base_url>/<year>/day/<day>/answer`, keeping its history in `state_dir`
+pub struct SubmitClient {
+    base_url: String,
+    session: String,
+    state_dir: PathBuf,
+    agent: ureq::Agent,
+}
+
+impl SubmitClient {
+    pub fn new(base_url: &str, session: &str, state_dir: impl Into<PathBuf>) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.trim().to_string(),
+            state_dir: state_dir.into(),
+            agent: ureq::AgentBuilder::new()
+                .timeout(Duration::from_secs(30))
+                .user_agent(concat!(
+                    env!("CARGO_PKG_NAME"),
+                    "/",
+                    env!("CARGO_PKG_VERSION")
+                ))
+                .build(),
+        }
+    }
+
+    fn history_path(&self, year: u16, day: u8) -> PathBuf {
+        self.state_dir.join(year.to_string()).join(format!("day{}.tsv", day))
+    }
+
+    pub fn submit(
+        &self,
+        year: u16,
+        day: u8,
+        part: u8,
+        answer: &Answer,
+    ) -> Result<Verdict, SubmitError> {
+        let answer = answer.to_string().trim().to_string();
+        let path = self.history_path(year, day);
+        let mut history = History::load(&path)?;
+
+        if let Some((_, _, verdict)) = history
+            .rejected
+            .iter()
+            .find(|(p, a, _)| *p == part && *a == answer)
+        {
+            return Err(SubmitError::PreviouslyRejected(verdict.clone()));
+        }
+        let now = now();
+        if history.cooldown_until > now {
+            return Err(SubmitError::CoolingDown(Duration::from_secs(
+                history.cooldown_until - now,
+            )));
+        }
+
+        let url = format!("{}/{}/day/{}/answer", self.base_url, year, day);
+        info!("Submitting {} for day {} part {} of {}", answer, day, part, year);
+        let response = self
+            .agent
+            .post(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .send_form(&[("level", &part.to_string()), ("answer", &answer)]);
+
+        let html = match response {
+            Ok(response) => response.into_string()?,
+            Err(ureq::Error::Status(400 | 401 | 403, _)) => return Err(SubmitError::Unauthorized),
+            Err(ureq::Error::Status(status, _)) => return Err(SubmitError::Http(status)),
+            Err(ureq::Error::Transport(e)) => return Err(SubmitError::Transport(e.to_string())),
+        };
+
+        let (verdict, cooldown) = parse_response(&html);
+        if let Some(cooldown) = cooldown {
+            history.cooldown_until = now + cooldown.as_secs();
+        }
+        if verdict.is_rejection() {
+            history.rejected.push((part, answer, verdict.clone()));
+        }
+        history.save(&path)?;
+
+        Ok(verdict)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_server;
+    use tempfile::TempDir;
+
+    const CORRECT: &str = "<main>\n<article><p>That's the right answer!  You are <span class=\"day-success\">one gold star</span> closer to finding the Chief Historian. <a href=\"/2024/day/1#part2\">[Continue to Part Two]</a></p></article>\n</main>";
+    const TOO_HIGH: &str = "<main>\n<article><p>That's not the right answer; your answer is too high.  If you're stuck, make sure you're using the full input data; there are also some general tips on the <a href=\"/2024/about\">about page</a>, or you can ask for hints on the <a href=\"https://www.reddit.com/r/adventofcode/\" target=\"_blank\">subreddit</a>.  Please wait one minute before trying again. <a href=\"/2024/day/1\">[Return to Day 1]</a></p></article>\n</main>";
+    const TOO_LOW: &str = "<article><p>That's not the right answer; your answer is too low.  Please wait 5 minutes before trying again.</p></article>";
+    const INCORRECT: &str = "<article><p>That's not the right answer.  If you're stuck, make sure you're using the full input data.</p></article>";
+    const TOO_RECENT: &str = "<main>\n<article><p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 5s left to wait. <a href=\"/2024/day/1\">[Return to Day 1]</a></p></article>\n</main>";
+    const WRONG_LEVEL: &str = "<article><p>You don't seem to be solving the right level.  Did you already complete it? <a href=\"/2024/day/1\">[Return to Day 1]</a></p></article>";
+
+    #[test]
+    fn test_parse_response() {
+        assert_eq!(parse_response(CORRECT), (Verdict::Correct, None));
+        assert_eq!(
+            parse_response(TOO_HIGH),
+            (Verdict::TooHigh, Some(Duration::from_secs(60)))
+        );
+        assert_eq!(
+            parse_response(TOO_LOW),
+            (Verdict::TooLow, Some(Duration::from_secs(300)))
+        );
+        assert_eq!(parse_response(INCORRECT), (Verdict::Incorrect, None));
+        assert_eq!(
+            parse_response(TOO_RECENT),
+            (Verdict::Wait(Duration::from_secs(65)), Some(Duration::from_secs(65)))
+        );
+        assert_eq!(parse_response(WRONG_LEVEL), (Verdict::WrongLevel, None));
+        assert!(matches!(
+            parse_response("<article><p>Something else</p></article>").0,
+            Verdict::Unknown(text) if text == "Something else"
+        ));
+    }
+
+    #[test]
+    fn test_submit_posts_form() {
+        let (base_url, server) = test_server::serve(vec![(200, CORRECT)]);
+        let dir = TempDir::new().unwrap();
+        let client = SubmitClient::new(&base_url, "secret", dir.path());
+
+        let verdict = client.submit(2024, 5, 2, &Answer::from(123u32)).unwrap();
+        assert_eq!(verdict, Verdict::Correct);
+
+        let requests = server.join().unwrap();
+        assert_eq!(requests[0].method, "POST");
+        assert_eq!(requests[0].url, "/2024/day/5/answer");
+        assert_eq!(requests[0].cookie.as_deref(), Some("session=secret"));
+        assert_eq!(requests[0].body, "level=2&answer=123");
+    }
+
+    #[test]
+    fn test_rejected_answers_are_not_resubmitted() {
+        let (base_url, server) = test_server::serve(vec![(200, INCORRECT), (200, CORRECT)]);
+        let dir = TempDir::new().unwrap();
+        let client = SubmitClient::new(&base_url, "secret", dir.path());
+
+        let wrong = Answer::from(41u32);
+        assert_eq!(client.submit(2024, 1, 1, &wrong).unwrap(), Verdict::Incorrect);
+
+        // A fresh client reads the history back from disk
+        let client = SubmitClient::new(&base_url, "secret", dir.path());
+        assert!(matches!(
+            client.submit(2024, 1, 1, &wrong),
+            Err(SubmitError::PreviouslyRejected(Verdict::Incorrect))
+        ));
+        assert_eq!(client.submit(2024, 1, 1, &Answer::from(42u32)).unwrap(), Verdict::Correct);
+
+        assert_eq!(server.join().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_history_keeps_multi_line_answers() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("day1.tsv");
+        let answers = ["#..#\n.##.\n#..#", "a\tb", "back\\slash\\n", "trailing\\"];
+        let history = History {
+            rejected: answers.iter().map(|answer| (2, answer.to_string(), Verdict::Incorrect)).collect(),
+            cooldown_until: 7,
+        };
+        history.save(&path).unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap().lines().count(), answers.len() + 1);
+
+        let loaded = History::load(&path).unwrap();
+        assert_eq!(loaded.cooldown_until, 7);
+        assert_eq!(loaded.rejected, history.rejected);
+    }
+
+    #[test]
+    fn test_cooldown_is_honoured() {
+        let (base_url, server) = test_server::serve(vec![(200, TOO_HIGH)]);
+        let dir = TempDir::new().unwrap();
+        let client = SubmitClient::new(&base_url, "secret", dir.path());
+
+        assert_eq!(client.submit(2024, 2, 1, &Answer::from(900u32)).unwrap(), Verdict::TooHigh);
+        match client.submit(2024, 2, 1, &Answer::from(800u32)) {
+            Err(SubmitError::CoolingDown(left)) => assert!(left <= Duration::from_secs(60)),
+            other => panic!("Expected a cooldown, got {:?}", other),
+        }
+
+        assert_eq!(server.join().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_wait_reply_is_not_a_rejection() {
+        let (base_url, server) = test_server::serve(vec![(200, TOO_RECENT)]);
+        let dir = TempDir::new().unwrap();
+        let client = SubmitClient::new(&base_url, "secret", dir.path());
+
+        let answer = Answer::from(7u32);
+        assert_eq!(
+            client.submit(2024, 3, 1, &answer).unwrap(),
+            Verdict::Wait(Duration::from_secs(65))
+        );
+        assert!(matches!(client.submit(2024, 3, 1, &answer), Err(SubmitError::CoolingDown(_))));
+        assert!(History::load(&client.history_path(2024, 3)).unwrap().rejected.is_empty());
+
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn test_unauthorized() {
+        let (base_url, server) = test_server::serve(vec![(400, "")]);
+        let dir = TempDir::new().unwrap();
+        let client = SubmitClient::new(&base_url, "expired", dir.path());
+
+        assert!(matches!(
+            client.submit(2024, 4, 1, &Answer::from(1u32)),
+            Err(SubmitError::Unauthorized)
+        ));
+
+        server.join().unwrap();
+    }
+}
@@ -0,0 +1,92 @@
+//! Deterministic generators for large, valid puzzle inputs.
+//!
+//! Every generator takes a seed and a size and always produces the same input for the same
+//! arguments, so benchmarks and property tests can be reproduced exactly. The generators for each
+//! event live in its year module, such as [`crate::y2024::synth`], and share the helpers here.
+
+/// Small, dependency free PRNG (SplitMix64), stable across platforms and releases
+pub struct SplitMix64 {
+    state: u64,
+}
+
+impl SplitMix64 {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform value in `0..n`
+    pub fn below(&mut self, n: usize) -> usize {
+        assert!(n > 0, "Cannot pick a value below 0");
+        (self.next_u64() % n as u64) as usize
+    }
+
+    /// Uniform value in `low..=high`
+    pub fn between(&mut self, low: i64, high: i64) -> i64 {
+        low + self.below((high - low + 1) as usize) as i64
+    }
+
+    /// Returns true with probability `numerator / denominator`
+    pub fn chance(&mut self, numerator: usize, denominator: usize) -> bool {
+        self.below(denominator) < numerator
+    }
+
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            let j = self.below(i + 1);
+            items.swap(i, j);
+        }
+    }
+}
+
+/// Side length of a square grid holding `scale` times as many cells as a `base` x `base` grid
+pub fn grid_side(base: usize, scale: usize) -> usize {
+    (base as f64 * (scale.max(1) as f64).sqrt()).round() as usize
+}
+
+/// Days of `year` that have a synthetic input generator
+pub fn days(year: u16) -> &'static [u8] {
+    match year {
+        2024 => &crate::y2024::synth::DAYS,
+        _ => &[],
+    }
+}
+
+/// Generates an input for `day` of `year` whose size is roughly that of a real puzzle input
+/// multiplied by `scale`
+pub fn generate(year: u16, day: u8, seed: u64, scale: usize) -> Option<String> {
+    match year {
+        2024 => crate::y2024::synth::generate(day, seed, scale),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generate_by_year() {
+        assert_eq!(days(2024).len(), 6);
+        assert!(generate(2024, 1, 7, 1).is_some());
+        assert_eq!(generate(2015, 1, 7, 1), None);
+        assert!(days(2015).is_empty());
+    }
+
+    #[test]
+    fn test_grid_side() {
+        assert_eq!(grid_side(130, 1), 130);
+        assert_eq!(grid_side(130, 4), 260);
+    }
+}
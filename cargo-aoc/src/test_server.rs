@@ -0,0 +1,53 @@
+//! Local stand-in for the puzzle website, so the HTTP clients can be tested without network
+//! access
+
+use std::thread::{self, JoinHandle};
+
+use tiny_http::{Response, Server};
+
+/// A request as seen by the stand-in server
+#[derive(Debug)]
+pub struct RecordedRequest {
+    pub method: String,
+    pub url: String,
+    pub cookie: Option<String>,
+    pub body: String,
+}
+
+/// Serves the canned `(status, body)` responses in order, one per request, then stops.
+/// Joining the handle returns the requests that were received.
+pub fn serve(responses: Vec<(u16, &'static str)>) -> (String, JoinHandle<Vec<RecordedRequest>>) {
+    let server = Server::http("127.0.0.1:0").expect("Failed to start test server");
+    let address = server
+        .server_addr()
+        .to_ip()
+        .expect("Test server is not listening on an IP address");
+
+    let handle = thread::spawn(move || {
+        let mut requests = Vec::new();
+        for (status, body) in responses {
+            let mut request = server.recv().expect("Test server failed to receive a request");
+            let mut request_body = String::new();
+            request
+                .as_reader()
+                .read_to_string(&mut request_body)
+                .expect("Failed to read request body");
+            requests.push(RecordedRequest {
+                method: request.method().to_string(),
+                url: request.url().to_string(),
+                cookie: request
+                    .headers()
+                    .iter()
+                    .find(|h| h.field.equiv("Cookie"))
+                    .map(|h| h.value.to_string()),
+                body: request_body,
+            });
+            request
+                .respond(Response::from_string(body).with_status_code(status))
+                .expect("Test server failed to respond");
+        }
+        requests
+    });
+
+    (format!("http://{}", address), handle)
+}
@@ -5,6 +5,8 @@ use aoc_generators::processors::regex_line_processor::{
 };
 
 use tracing::{debug, info};
+use crate::answer::Answer;
+use crate::solver::Solver;
 
 pub struct Day1LineProcessor {
     regex_line_processor: RegexLineProcessor,
@@ -21,6 +23,12 @@ impl Day1LineProcessor {
     }
 }
 
+impl Default for Day1LineProcessor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl LineProcessor for Day1LineProcessor {
     type Item = (i32, i32);
     type ProcessorError = String;
@@ -54,7 +62,7 @@ pub fn input_generator(input: &str) -> Vec<(i32, i32)> {
 }
 
 #[aoc(day1, part2)]
-fn part_2(input: &[(i32, i32)]) -> u32 {
+pub fn part_2(input: &[(i32, i32)]) -> Answer {
     info!("*** Start Part 2 ***");
 
     let mut first_values: Vec<i32> = Vec::new();
@@ -76,11 +84,11 @@ fn part_2(input: &[(i32, i32)]) -> u32 {
     debug!("Result:       {:?}", result);
 
     info!("*** End Part 2 ***");
-    result as u32
+    result.into()
 }
 
 #[aoc(day1, part1)]
-pub fn part_1(input: &[(i32, i32)]) -> u32 {
+pub fn part_1(input: &[(i32, i32)]) -> Answer {
     info!("*** Start Part 1 ***");
 
     let mut first_values: Vec<i32> = Vec::new();
@@ -97,17 +105,17 @@ pub fn part_1(input: &[(i32, i32)]) -> u32 {
     first_values.sort();
     second_values.sort();
 
-    let mut sum = 0;
+    let mut sum = 0u64;
     for i in 0..first_values.len() {
-        let difference = first_values[i] - second_values[i];
+        let difference = first_values[i].abs_diff(second_values[i]);
 
-        sum += difference.abs();
+        sum += u64::from(difference);
     }
 
     info!("Part 1 Result: {}", sum);
 
     info!("*** End Part 1 ***");
-    sum as u32
+    sum.into()
 }
 
 fn extract_values_from_tuples(
@@ -122,16 +130,36 @@ fn extract_values_from_tuples(
     });
 }
 
+pub struct Day1;
+
+impl Solver for Day1 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 1;
+    type Input = Vec<(i32, i32)>;
+    type Output = Answer;
+
+    fn parse(input: &str) -> Self::Input {
+        input_generator(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Output {
+        part_1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Option<Self::Output> {
+        Some(part_2(input))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::fs;
+    use crate::fixtures;
 
     #[test]
+    #[allow(clippy::useless_vec)]
     fn test_day_1_file_processor() {
-        let test_input_file = "resources/test_input_1";
-
-        let binding = fs::read_to_string(test_input_file).expect("failed to read file");
+        let binding = fixtures::load(2024, "day1.txt");
         let lines = binding.lines().collect::<Vec<&str>>();
 
         let processor = Day1LineProcessor::new();
@@ -0,0 +1,156 @@
+use ndarray::Array2;
+use tracing::debug;
+use crate::answer::Answer;
+use crate::grid::{Direction, Grid, Position};
+use crate::solver::Solver;
+
+/// Height of cells marked `.`, which the smaller examples use for ground no trail can cross
+pub const IMPASSABLE: u8 = u8::MAX;
+
+const TRAIL_END: u8 = 9;
+
+/// Height of every cell of the map, [`IMPASSABLE`] where there is no ground
+#[aoc_generator(day10)]
+pub fn input_generator(input: &str) -> Grid<u8> {
+    Grid::new(Grid::parse(input).into_cells().mapv(|c| match c {
+        '.' => IMPASSABLE,
+        _ => c.to_digit(10).unwrap_or_else(|| panic!("Invalid height: {}", c)) as u8,
+    }))
+}
+
+/// Score and rating of every cell: how many distinct 9s can be reached from it by climbing one
+/// height at a time, and by how many distinct trails
+#[derive(Debug, Clone)]
+struct Trails {
+    /// Cells a trail can start from
+    trailheads: Vec<Position>,
+    scores: Array2<usize>,
+    ratings: Array2<u64>,
+}
+
+fn uphill_neighbours(board: &Grid<u8>, position: Position) -> impl Iterator<Item = Position> + '_ {
+    let next = board[position].wrapping_add(1);
+    Direction::ALL
+        .into_iter()
+        .filter_map(move |direction| board.step(position, direction))
+        .filter(move |&neighbour| board[neighbour] == next)
+}
+
+/// Works down from the 9s, so every cell's uphill neighbours are finished before it is reached.
+/// The 9s reachable from a cell are kept as a bit set over all the 9s, letting paths that meet
+/// again further down be merged without counting their ends twice.
+fn trails(board: &Grid<u8>) -> Trails {
+    let mut by_height = vec![Vec::new(); TRAIL_END as usize + 1];
+    for (position, &height) in board.indexed_iter() {
+        if height <= TRAIL_END {
+            by_height[height as usize].push(position);
+        }
+    }
+
+    let ends = &by_height[TRAIL_END as usize];
+    let words = ends.len().div_ceil(64);
+    debug!("{} trail ends", ends.len());
+
+    let mut reachable = Array2::from_elem(board.dim(), Vec::new());
+    let mut ratings = Array2::zeros(board.dim());
+    for (i, &end) in ends.iter().enumerate() {
+        let mut bits = vec![0u64; words];
+        bits[i / 64] |= 1 << (i % 64);
+        reachable[end] = bits;
+        ratings[end] = 1;
+    }
+
+    for height in (0..TRAIL_END as usize).rev() {
+        for &position in &by_height[height] {
+            let mut bits = vec![0u64; words];
+            let mut rating = 0;
+            for neighbour in uphill_neighbours(board, position) {
+                for (word, other) in bits.iter_mut().zip(&reachable[neighbour]) {
+                    *word |= other;
+                }
+                rating += ratings[neighbour];
+            }
+            reachable[position] = bits;
+            ratings[position] = rating;
+        }
+    }
+
+    Trails {
+        trailheads: std::mem::take(&mut by_height[0]),
+        scores: reachable.mapv(|bits| bits.iter().map(|word| word.count_ones() as usize).sum()),
+        ratings,
+    }
+}
+
+#[aoc(day10, part1)]
+pub fn part_1(board: &Grid<u8>) -> Answer {
+    let trails = trails(board);
+    trails.trailheads.iter().map(|&position| trails.scores[position]).sum::<usize>().into()
+}
+
+#[aoc(day10, part2)]
+pub fn part_2(board: &Grid<u8>) -> Answer {
+    let trails = trails(board);
+    trails.trailheads.iter().map(|&position| trails.ratings[position]).sum::<u64>().into()
+}
+
+pub struct Day10;
+
+impl Solver for Day10 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 10;
+    type Input = Grid<u8>;
+    type Output = Answer;
+
+    fn parse(input: &str) -> Self::Input {
+        input_generator(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Output {
+        part_1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Option<Self::Output> {
+        Some(part_2(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures;
+
+    #[test]
+    fn test_input_generator() {
+        let board = input_generator("0123\n.98.");
+        assert_eq!(board.cells().row(0).to_vec(), vec![0, 1, 2, 3]);
+        assert_eq!(board.cells().row(1).to_vec(), vec![IMPASSABLE, 9, 8, IMPASSABLE]);
+    }
+
+    #[test]
+    fn test_trails() {
+        // The single trailhead example, which reaches two 9s
+        let board = input_generator("...0...\n...1...\n...2...\n6543456\n7.....7\n8.....8\n9.....9");
+        let rated = trails(&board);
+        assert_eq!(rated.scores[(0, 3)], 2);
+        assert_eq!(rated.ratings[(0, 3)], 2);
+
+        // Three trails to the same 9
+        let board = input_generator(".....0.\n..4321.\n..5..2.\n..6543.\n..7..4.\n..8765.\n..9....");
+        let rated = trails(&board);
+        assert_eq!(rated.scores[(0, 5)], 1);
+        assert_eq!(rated.ratings[(0, 5)], 3);
+    }
+
+    #[test]
+    fn test_part_1() {
+        let board = input_generator(&fixtures::load(2024, "day10.txt"));
+        assert_eq!(part_1(&board), 36);
+    }
+
+    #[test]
+    fn test_part_2() {
+        let board = input_generator(&fixtures::load(2024, "day10.txt"));
+        assert_eq!(part_2(&board), 81);
+    }
+}
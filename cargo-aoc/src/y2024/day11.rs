@@ -0,0 +1,153 @@
+use std::collections::HashMap;
+use tracing::debug;
+use crate::answer::Answer;
+use crate::parse::unsigned_numbers;
+use crate::solver::Solver;
+
+/// The stones in a line, kept as how many stones carry each number. Stones never affect their
+/// neighbours and order does not matter for counting, so equal stones can be blinked together.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Stones {
+    counts: HashMap<u64, u64>,
+}
+
+impl Stones {
+    pub fn new(numbers: impl IntoIterator<Item = u64>) -> Self {
+        let mut stones = Self::default();
+        for number in numbers {
+            *stones.counts.entry(number).or_default() += 1;
+        }
+        stones
+    }
+
+    /// Total number of stones
+    pub fn len(&self) -> u64 {
+        self.counts.values().sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.counts.is_empty()
+    }
+
+    /// How many stones carry `number`
+    pub fn count(&self, number: u64) -> u64 {
+        self.counts.get(&number).copied().unwrap_or_default()
+    }
+
+    /// The stones after blinking `times` times
+    pub fn blink(&self, times: usize) -> Stones {
+        let mut stones = self.clone();
+        for i in 0..times {
+            let mut next = HashMap::with_capacity(stones.counts.len());
+            for (&number, &count) in &stones.counts {
+                let (first, second) = change(number);
+                *next.entry(first).or_default() += count;
+                if let Some(second) = second {
+                    *next.entry(second).or_default() += count;
+                }
+            }
+            stones.counts = next;
+            debug!("Blink {}: {} distinct numbers", i + 1, stones.counts.len());
+        }
+        stones
+    }
+}
+
+/// What a single stone becomes, as one or two stones
+fn change(number: u64) -> (u64, Option<u64>) {
+    if number == 0 {
+        return (1, None);
+    }
+    let digits = number.ilog10() + 1;
+    if digits.is_multiple_of(2) {
+        let half = 10u64.pow(digits / 2);
+        (number / half, Some(number % half))
+    } else {
+        (number * 2024, None)
+    }
+}
+
+#[aoc_generator(day11)]
+pub fn input_generator(input: &str) -> Stones {
+    Stones::new(unsigned_numbers(input))
+}
+
+#[aoc(day11, part1)]
+pub fn part_1(stones: &Stones) -> Answer {
+    stones.blink(25).len().into()
+}
+
+#[aoc(day11, part2)]
+pub fn part_2(stones: &Stones) -> Answer {
+    stones.blink(75).len().into()
+}
+
+pub struct Day11;
+
+impl Solver for Day11 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 11;
+    type Input = Stones;
+    type Output = Answer;
+
+    fn parse(input: &str) -> Self::Input {
+        input_generator(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Output {
+        part_1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Option<Self::Output> {
+        Some(part_2(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+    use super::*;
+    use crate::fixtures;
+
+    #[test]
+    fn test_change() {
+        assert_eq!(change(0), (1, None));
+        assert_eq!(change(1), (2024, None));
+        assert_eq!(change(10), (1, Some(0)));
+        assert_eq!(change(99), (9, Some(9)));
+        assert_eq!(change(1000), (10, Some(0)));
+        assert_eq!(change(999), (2021976, None));
+    }
+
+    #[test]
+    fn test_blink() {
+        let stones = input_generator("0 1 10 99 999");
+        // 1 2024 1 0 9 9 2021976
+        let blinked = stones.blink(1);
+        assert_eq!(blinked.len(), 7);
+        assert_eq!(blinked.count(1), 2);
+        assert_eq!(blinked.count(9), 2);
+        assert_eq!(stones.blink(0), stones);
+
+        let stones = input_generator(&fixtures::load(2024, "day11.txt"));
+        assert_eq!(stones.blink(6).len(), 22);
+        assert_eq!(stones.blink(3).blink(3), stones.blink(6));
+    }
+
+    #[test]
+    fn test_part_1() {
+        let stones = input_generator(&fixtures::load(2024, "day11.txt"));
+        assert_eq!(part_1(&stones), 55312);
+    }
+
+    #[test]
+    fn test_part_2_is_fast() {
+        let stones = input_generator(&fixtures::load(2024, "day11.txt"));
+        let start = Instant::now();
+        let answer = part_2(&stones);
+        let elapsed = start.elapsed();
+
+        assert_eq!(answer, 65601038650482u64);
+        assert!(elapsed < Duration::from_secs(1), "75 blinks took {:?}", elapsed);
+    }
+}
@@ -0,0 +1,150 @@
+use std::collections::VecDeque;
+use ndarray::Array2;
+use tracing::debug;
+use crate::answer::Answer;
+use crate::grid::{Direction, Grid};
+use crate::solver::Solver;
+
+/// A connected group of plots growing the same plant
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Region {
+    pub plant: char,
+    pub area: usize,
+    pub perimeter: usize,
+    /// A polygon has as many corners as sides, so this is also the number of sides
+    pub corners: usize,
+}
+
+#[aoc_generator(day12)]
+pub fn input_generator(input: &str) -> Grid<char> {
+    Grid::parse(input)
+}
+
+/// Labels every region with a breadth first flood fill, measuring each as it is filled
+pub fn regions(board: &Grid<char>) -> Vec<Region> {
+    let same = |position: Option<_>, plant| position.is_some_and(|p| board[p] == plant);
+
+    let mut labelled = Array2::from_elem(board.dim(), false);
+    let mut regions = Vec::new();
+    let mut queue = VecDeque::new();
+    for (start, &plant) in board.indexed_iter() {
+        if labelled[start] {
+            continue;
+        }
+        labelled[start] = true;
+        queue.push_back(start);
+
+        let mut region = Region { plant, area: 0, perimeter: 0, corners: 0 };
+        while let Some(position) = queue.pop_front() {
+            region.area += 1;
+            for direction in Direction::ALL {
+                match board.step(position, direction) {
+                    Some(next) if board[next] == plant => {
+                        if !labelled[next] {
+                            labelled[next] = true;
+                            queue.push_back(next);
+                        }
+                    }
+                    _ => region.perimeter += 1,
+                }
+            }
+
+            // Each pair of adjacent directions meets at one corner of the plot, which is a corner
+            // of the region when both sides are fences (outer) or neither is but the plot
+            // diagonally between them is another plant (inner)
+            for a in Direction::ALL {
+                let b = a.turn_right();
+                let (side_a, side_b) = (board.step(position, a), board.step(position, b));
+                let diagonal = side_a.and_then(|side| board.step(side, b));
+                match (same(side_a, plant), same(side_b, plant)) {
+                    (false, false) => region.corners += 1,
+                    (true, true) if !same(diagonal, plant) => region.corners += 1,
+                    _ => (),
+                }
+            }
+        }
+        debug!("Region {:?}", region);
+        regions.push(region);
+    }
+    regions
+}
+
+#[aoc(day12, part1)]
+pub fn part_1(board: &Grid<char>) -> Answer {
+    regions(board).iter().map(|region| region.area * region.perimeter).sum::<usize>().into()
+}
+
+#[aoc(day12, part2)]
+pub fn part_2(board: &Grid<char>) -> Answer {
+    regions(board).iter().map(|region| region.area * region.corners).sum::<usize>().into()
+}
+
+pub struct Day12;
+
+impl Solver for Day12 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 12;
+    type Input = Grid<char>;
+    type Output = Answer;
+
+    fn parse(input: &str) -> Self::Input {
+        input_generator(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Output {
+        part_1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Option<Self::Output> {
+        Some(part_2(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures;
+
+    /// The example gardens in the order the puzzle shows them, with both answers
+    const GARDENS: [(&str, usize, usize); 5] = [
+        ("day12.txt", 140, 80),
+        ("day12_2.txt", 772, 436),
+        ("day12_3.txt", 1930, 1206),
+        ("day12_4.txt", 692, 236),
+        ("day12_5.txt", 1184, 368),
+    ];
+
+    #[test]
+    fn test_regions() {
+        let board = input_generator(&fixtures::load(2024, "day12.txt"));
+        let found = regions(&board);
+        let summary = found.iter().map(|r| (r.plant, r.area, r.perimeter, r.corners)).collect::<Vec<_>>();
+        assert_eq!(
+            summary,
+            vec![('A', 4, 10, 4), ('B', 4, 8, 4), ('C', 4, 10, 8), ('D', 1, 4, 4), ('E', 3, 8, 4)]
+        );
+
+        // Regions of the same plant that do not touch are kept apart, including ones enclosed by
+        // another region
+        let board = input_generator(&fixtures::load(2024, "day12_2.txt"));
+        let found = regions(&board);
+        assert_eq!(found.len(), 5);
+        assert_eq!(found[0], Region { plant: 'O', area: 21, perimeter: 36, corners: 20 });
+    }
+
+    #[test]
+    fn test_part_1() {
+        for (fixture, expected, _) in GARDENS {
+            let board = input_generator(&fixtures::load(2024, fixture));
+            assert_eq!(part_1(&board), expected, "{}", fixture);
+        }
+    }
+
+    #[test]
+    fn test_part_2() {
+        for (fixture, _, expected) in GARDENS {
+            let board = input_generator(&fixtures::load(2024, fixture));
+            assert_eq!(part_2(&board), expected, "{}", fixture);
+        }
+    }
+}
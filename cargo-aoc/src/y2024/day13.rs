@@ -0,0 +1,230 @@
+use tracing::debug;
+use crate::answer::Answer;
+use crate::parse::Parser;
+use crate::solver::Solver;
+
+/// How far the prizes really are in part 2, on both axes
+const PRIZE_OFFSET: i128 = 10_000_000_000_000;
+
+const A_COST: i128 = 3;
+const B_COST: i128 = 1;
+
+type Vector = (i128, i128);
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Machine {
+    pub a: Vector,
+    pub b: Vector,
+    pub prize: Vector,
+}
+
+impl Machine {
+    /// The cheapest number of presses of A and B that reach the prize exactly, if there are any
+    pub fn presses(&self) -> Option<(i128, i128)> {
+        let Machine { a, b, prize } = *self;
+        let determinant = a.0 * b.1 - a.1 * b.0;
+        if determinant != 0 {
+            // Cramer's rule. The only solution is unique, so it is also the cheapest.
+            let a_presses = exact_div(prize.0 * b.1 - prize.1 * b.0, determinant)?;
+            let b_presses = exact_div(a.0 * prize.1 - a.1 * prize.0, determinant)?;
+            return (a_presses >= 0 && b_presses >= 0).then_some((a_presses, b_presses));
+        }
+
+        // The buttons move the claw along the same line, so the prize has to be on it too
+        if a.0 * prize.1 - a.1 * prize.0 != 0 || b.0 * prize.1 - b.1 * prize.0 != 0 {
+            return None;
+        }
+        match (a == (0, 0), b == (0, 0)) {
+            (true, true) => (prize == (0, 0)).then_some((0, 0)),
+            (true, false) => multiple(b, prize).map(|b_presses| (0, b_presses)),
+            (false, true) => multiple(a, prize).map(|a_presses| (a_presses, 0)),
+            // Neither button is still, so a vertical line is the only way for an axis to be unused
+            (false, false) if a.0 == 0 => cheapest_on_line(a.1, b.1, prize.1),
+            (false, false) => cheapest_on_line(a.0, b.0, prize.0),
+        }
+    }
+
+    pub fn tokens(&self) -> Option<i128> {
+        self.presses().map(|(a, b)| a * A_COST + b * B_COST)
+    }
+}
+
+fn exact_div(numerator: i128, denominator: i128) -> Option<i128> {
+    (numerator % denominator == 0).then(|| numerator / denominator)
+}
+
+/// Presses of a single button that reach the prize exactly
+fn multiple(button: Vector, prize: Vector) -> Option<i128> {
+    let axis = if button.0 != 0 { (button.0, prize.0) } else { (button.1, prize.1) };
+    exact_div(axis.1, axis.0).filter(|&presses| presses >= 0 && (button.0 * presses, button.1 * presses) == prize)
+}
+
+/// Returns `(gcd, x, y)` with `a * x + b * y == gcd`
+fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    if b == 0 {
+        (a, 1, 0)
+    } else {
+        let (gcd, x, y) = extended_gcd(b, a % b);
+        (gcd, y, x - (a / b) * y)
+    }
+}
+
+/// The cheapest non-negative `a * a_step + b * b_step == target`, for positive steps.
+///
+/// Solutions are `a = a0 + k * b_step / gcd` and `b = b0 - k * a_step / gcd`, and the cost is
+/// linear in `k`, so the cheapest is at one end of the range of `k` keeping both counts
+/// non-negative.
+fn cheapest_on_line(a_step: i128, b_step: i128, target: i128) -> Option<(i128, i128)> {
+    let (gcd, x, y) = extended_gcd(a_step, b_step);
+    let scale = exact_div(target, gcd)?;
+    let (a0, b0) = (x * scale, y * scale);
+    let (a_stride, b_stride) = (b_step / gcd, a_step / gcd);
+
+    let lowest = (-a0).div_euclid(a_stride) + i128::from((-a0).rem_euclid(a_stride) != 0);
+    let highest = b0.div_euclid(b_stride);
+    if lowest > highest {
+        return None;
+    }
+    [lowest, highest]
+        .map(|k| (a0 + k * a_stride, b0 - k * b_stride))
+        .into_iter()
+        .min_by_key(|(a, b)| a * A_COST + b * B_COST)
+}
+
+#[aoc_generator(day13)]
+pub fn input_generator(input: &str) -> Vec<Machine> {
+    let mut parser = Parser::new(input);
+    let mut machines = Vec::new();
+    while !parser.is_empty() {
+        let machine = machine(&mut parser)
+            .unwrap_or_else(|| panic!("Invalid machine {}", machines.len() + 1));
+        machines.push(machine);
+        // Machines are separated by blank lines
+        while parser.newline() {}
+    }
+    machines
+}
+
+/// Parses one three line machine description
+fn machine(parser: &mut Parser) -> Option<Machine> {
+    let mut vector = |prefix: &[u8], separator: &[u8]| {
+        let x = parser.literal(prefix).then(|| parser.unsigned())??;
+        let y = parser.literal(separator).then(|| parser.unsigned())??;
+        (parser.newline() || parser.is_empty()).then_some((x, y))
+    };
+    Some(Machine {
+        a: vector(b"Button A: X+", b", Y+")?,
+        b: vector(b"Button B: X+", b", Y+")?,
+        prize: vector(b"Prize: X=", b", Y=")?,
+    })
+}
+
+fn total_tokens(machines: impl Iterator<Item = Machine>) -> i128 {
+    machines
+        .filter_map(|machine| {
+            let tokens = machine.tokens();
+            debug!("{:?}: {:?}", machine, tokens);
+            tokens
+        })
+        .sum()
+}
+
+#[aoc(day13, part1)]
+pub fn part_1(machines: &[Machine]) -> Answer {
+    total_tokens(machines.iter().copied()).into()
+}
+
+#[aoc(day13, part2)]
+pub fn part_2(machines: &[Machine]) -> Answer {
+    let moved = machines.iter().map(|machine| Machine {
+        prize: (machine.prize.0 + PRIZE_OFFSET, machine.prize.1 + PRIZE_OFFSET),
+        ..*machine
+    });
+    total_tokens(moved).into()
+}
+
+pub struct Day13;
+
+impl Solver for Day13 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 13;
+    type Input = Vec<Machine>;
+    type Output = Answer;
+
+    fn parse(input: &str) -> Self::Input {
+        input_generator(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Output {
+        part_1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Option<Self::Output> {
+        Some(part_2(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures;
+
+    fn machine(a: Vector, b: Vector, prize: Vector) -> Machine {
+        Machine { a, b, prize }
+    }
+
+    #[test]
+    fn test_input_generator() {
+        let machines = input_generator(&fixtures::load(2024, "day13.txt"));
+        assert_eq!(machines.len(), 4);
+        assert_eq!(machines[0], machine((94, 34), (22, 67), (8400, 5400)));
+        assert_eq!(machines[3], machine((69, 23), (27, 71), (18641, 10279)));
+
+        let machines = input_generator("Button A: X+1, Y+2\r\nButton B: X+3, Y+4\r\nPrize: X=5, Y=6\r\n");
+        assert_eq!(machines, vec![machine((1, 2), (3, 4), (5, 6))]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid machine 2")]
+    fn test_input_generator_invalid() {
+        input_generator("Button A: X+1, Y+2\nButton B: X+3, Y+4\nPrize: X=5, Y=6\n\nButton A: X+1\n");
+    }
+
+    #[test]
+    fn test_presses() {
+        assert_eq!(machine((94, 34), (22, 67), (8400, 5400)).presses(), Some((80, 40)));
+        // Not a whole number of presses
+        assert_eq!(machine((26, 66), (67, 21), (12748, 12176)).presses(), None);
+        // Only reachable by pressing a button a negative number of times
+        assert_eq!(machine((1, 0), (0, 1), (-2, 3)).presses(), None);
+    }
+
+    #[test]
+    fn test_presses_collinear() {
+        // A moves three times as far as B for three times the cost, so either is as cheap
+        assert_eq!(machine((3, 3), (1, 1), (7, 7)).tokens(), Some(7));
+        // B is the cheaper way along the line, but it takes a press of A to land on the prize
+        assert_eq!(machine((2, 4), (4, 8), (6, 12)).presses(), Some((1, 1)));
+        assert_eq!(machine((2, 4), (4, 8), (12, 24)).presses(), Some((0, 3)));
+        // A goes further per token than B
+        assert_eq!(machine((10, 0), (1, 0), (21, 0)).presses(), Some((2, 1)));
+        assert_eq!(machine((2, 2), (4, 4), (5, 5)).presses(), None);
+        assert_eq!(machine((2, 2), (4, 4), (6, 7)).presses(), None);
+        assert_eq!(machine((0, 2), (0, 3), (0, 7)).presses(), Some((2, 1)));
+        assert_eq!(machine((0, 0), (2, 1), (6, 3)).presses(), Some((0, 3)));
+        assert_eq!(machine((0, 0), (0, 0), (0, 0)).presses(), Some((0, 0)));
+        assert_eq!(machine((0, 0), (0, 0), (1, 0)).presses(), None);
+    }
+
+    #[test]
+    fn test_part_1() {
+        let machines = input_generator(&fixtures::load(2024, "day13.txt"));
+        assert_eq!(part_1(&machines), 480);
+    }
+
+    #[test]
+    fn test_part_2() {
+        let machines = input_generator(&fixtures::load(2024, "day13.txt"));
+        assert_eq!(part_2(&machines), 875318608908i128);
+    }
+}
@@ -0,0 +1,225 @@
+use tracing::debug;
+use crate::answer::Answer;
+use crate::parse::Parser;
+use crate::solver::Solver;
+
+/// Width and height of the bathroom in real puzzle inputs
+pub const BATHROOM: (i64, i64) = (101, 103);
+
+/// Width and height of the bathroom in the puzzle example
+pub const EXAMPLE_BATHROOM: (i64, i64) = (11, 7);
+
+const SAFETY_SECONDS: i64 = 100;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Robot {
+    pub position: (i64, i64),
+    pub velocity: (i64, i64),
+}
+
+impl Robot {
+    /// Where the robot is after `seconds`, wrapping around the edges of a bathroom of `size`
+    pub fn position_after(&self, seconds: i64, (width, height): (i64, i64)) -> (i64, i64) {
+        (
+            (self.position.0 + self.velocity.0 * seconds).rem_euclid(width),
+            (self.position.1 + self.velocity.1 * seconds).rem_euclid(height),
+        )
+    }
+}
+
+/// The robots along with the size of the bathroom they move around
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Bathroom {
+    pub size: (i64, i64),
+    pub robots: Vec<Robot>,
+}
+
+impl Bathroom {
+    /// The input does not give the bathroom's size, so it is the example's when every robot
+    /// starts inside that and a real one's otherwise
+    pub fn new(robots: Vec<Robot>) -> Self {
+        let fits = |(width, height): (i64, i64)| {
+            robots
+                .iter()
+                .all(|robot| (0..width).contains(&robot.position.0) && (0..height).contains(&robot.position.1))
+        };
+        let size = if fits(EXAMPLE_BATHROOM) { EXAMPLE_BATHROOM } else { BATHROOM };
+        if !fits(size) {
+            panic!("Robots are outside a {}x{} bathroom", size.0, size.1);
+        }
+        debug!("Bathroom is {}x{}", size.0, size.1);
+        Self { size, robots }
+    }
+}
+
+#[aoc_generator(day14)]
+pub fn input_generator(input: &str) -> Bathroom {
+    let mut parser = Parser::new(input);
+    let mut robots = Vec::new();
+    while !parser.is_empty() {
+        let robot = robot(&mut parser).unwrap_or_else(|| panic!("Invalid robot {}", robots.len() + 1));
+        robots.push(robot);
+        while parser.newline() {}
+    }
+    Bathroom::new(robots)
+}
+
+/// Parses a `p=x,y v=dx,dy` line
+fn robot(parser: &mut Parser) -> Option<Robot> {
+    let mut pair = |prefix: &[u8]| {
+        let x = parser.literal(prefix).then(|| parser.signed())??;
+        let y = parser.skip(b',').then(|| parser.signed())??;
+        Some((x, y))
+    };
+    let position = pair(b"p=")?;
+    let velocity = pair(b" v=")?;
+    parser.at_line_end().then_some(Robot { position, velocity })
+}
+
+/// Product of the number of robots in each quadrant after 100 seconds. Robots exactly in the
+/// middle row or column are in none.
+pub fn safety_factor(robots: &[Robot], size: (i64, i64)) -> usize {
+    let (middle_x, middle_y) = (size.0 / 2, size.1 / 2);
+    let mut quadrants = [0; 4];
+    for robot in robots {
+        let (x, y) = robot.position_after(SAFETY_SECONDS, size);
+        if x != middle_x && y != middle_y {
+            quadrants[usize::from(x > middle_x) + 2 * usize::from(y > middle_y)] += 1;
+        }
+    }
+    debug!("Quadrants: {:?}", quadrants);
+    quadrants.iter().product()
+}
+
+/// Variance of the values times their count squared, which keeps it an integer
+fn scaled_variance(values: impl Iterator<Item = i64>) -> i64 {
+    let (count, sum, squares) =
+        values.fold((0, 0, 0), |(count, sum, squares), v| (count + 1, sum + v, squares + v * v));
+    count * squares - sum * sum
+}
+
+/// The first second at which the robots draw the Christmas tree.
+///
+/// Horizontal positions repeat every `width` seconds and vertical ones every `height`, and the
+/// tree packs the robots together, so each axis is at its tightest at the tree's second modulo
+/// that axis' period. With both sides prime, the Chinese remainder theorem gives the one second
+/// below `width * height` matching both.
+pub fn tree_second(robots: &[Robot], size: (i64, i64)) -> i64 {
+    let (width, height) = size;
+    let tightest = |period: i64, axis: fn((i64, i64)) -> i64| {
+        (0..period)
+            .min_by_key(|&seconds| {
+                scaled_variance(robots.iter().map(|robot| axis(robot.position_after(seconds, size))))
+            })
+            .unwrap()
+    };
+    let x_second = tightest(width, |(x, _)| x);
+    let y_second = tightest(height, |(_, y)| y);
+    debug!("Tightest x after {}, y after {}", x_second, y_second);
+
+    (0..height)
+        .map(|k| x_second + k * width)
+        .find(|seconds| seconds % height == y_second)
+        .expect("Bathroom sides are not coprime")
+}
+
+#[aoc(day14, part1)]
+pub fn part_1(bathroom: &Bathroom) -> Answer {
+    safety_factor(&bathroom.robots, bathroom.size).into()
+}
+
+#[aoc(day14, part2)]
+pub fn part_2(bathroom: &Bathroom) -> Answer {
+    tree_second(&bathroom.robots, bathroom.size).into()
+}
+
+pub struct Day14;
+
+impl Solver for Day14 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 14;
+    type Input = Bathroom;
+    type Output = Answer;
+
+    fn parse(input: &str) -> Self::Input {
+        input_generator(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Output {
+        part_1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Option<Self::Output> {
+        Some(part_2(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures;
+    use crate::synth::SplitMix64;
+
+    #[test]
+    fn test_input_generator() {
+        let bathroom = input_generator(&fixtures::load(2024, "day14.txt"));
+        assert_eq!(bathroom.size, EXAMPLE_BATHROOM);
+        assert_eq!(bathroom.robots.len(), 12);
+        assert_eq!(bathroom.robots[0], Robot { position: (0, 4), velocity: (3, -3) });
+        assert_eq!(bathroom.robots[11], Robot { position: (9, 5), velocity: (-3, -3) });
+
+        // A robot beyond the example's bathroom makes it a real one
+        let bathroom = input_generator("p=0,4 v=3,-3\np=100,102 v=-1,1\n");
+        assert_eq!(bathroom.size, BATHROOM);
+    }
+
+    #[test]
+    #[should_panic(expected = "Robots are outside a 101x103 bathroom")]
+    fn test_input_generator_too_large() {
+        input_generator("p=0,4 v=3,-3\np=101,3 v=-1,1\n");
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid robot 2")]
+    fn test_input_generator_invalid() {
+        input_generator("p=0,4 v=3,-3\np=6,3 v=-1\n");
+    }
+
+    #[test]
+    fn test_position_after() {
+        // The robot followed through the first five seconds in the puzzle description
+        let robot = Robot { position: (2, 4), velocity: (2, -3) };
+        let positions = (0..=5).map(|s| robot.position_after(s, EXAMPLE_BATHROOM)).collect::<Vec<_>>();
+        assert_eq!(positions, vec![(2, 4), (4, 1), (6, 5), (8, 2), (10, 6), (1, 3)]);
+    }
+
+    #[test]
+    fn test_safety_factor() {
+        let bathroom = input_generator(&fixtures::load(2024, "day14.txt"));
+        assert_eq!(safety_factor(&bathroom.robots, EXAMPLE_BATHROOM), 12);
+    }
+
+    #[test]
+    fn test_part_1() {
+        let bathroom = input_generator(&fixtures::load(2024, "day14.txt"));
+        assert_eq!(part_1(&bathroom), 12);
+    }
+
+    #[test]
+    fn test_tree_second() {
+        // Scatter robots so that they all fall inside a small square at a chosen second and are
+        // spread over the whole bathroom otherwise
+        let tree = 6475;
+        let mut rng = SplitMix64::new(14);
+        let robots = (0..400)
+            .map(|_| {
+                let velocity = (rng.between(-100, 100), rng.between(-100, 100));
+                let target = Robot { position: (40 + rng.between(0, 20), 40 + rng.between(0, 20)), velocity };
+                Robot { position: target.position_after(-tree, BATHROOM), velocity }
+            })
+            .collect::<Vec<Robot>>();
+
+        assert_eq!(tree_second(&robots, BATHROOM), tree);
+        assert_eq!(part_2(&Bathroom::new(robots)), tree);
+    }
+}
@@ -0,0 +1,241 @@
+use std::collections::HashSet;
+use tracing::debug;
+use crate::answer::Answer;
+use crate::grid::{Direction, Grid, Position};
+use crate::solver::Solver;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Warehouse {
+    pub map: Grid<char>,
+    pub moves: Vec<Direction>,
+}
+
+#[aoc_generator(day15)]
+pub fn input_generator(input: &str) -> Warehouse {
+    let input = input.replace("\r\n", "\n");
+    let (map, moves) = input
+        .split_once("\n\n")
+        .expect("Expected the map and the moves separated by a blank line");
+
+    let moves = moves
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .map(|c| Direction::from_char(c).unwrap_or_else(|| panic!("Invalid move: {}", c)))
+        .collect();
+    Warehouse {
+        map: Grid::parse(map),
+        moves,
+    }
+}
+
+/// The map as it looks in the second warehouse, where everything but the robot is twice as wide
+pub fn widen(map: &Grid<char>) -> Grid<char> {
+    let text = map
+        .to_string()
+        .chars()
+        .map(|c| match c {
+            '#' => "##",
+            'O' => "[]",
+            '.' => "..",
+            '@' => "@.",
+            '\n' => "\n",
+            _ => panic!("Invalid map cell: {}", c),
+        })
+        .collect::<String>();
+    Grid::parse(&text)
+}
+
+/// The other half of a wide box
+fn partner(map: &Grid<char>, position: Position) -> Option<Position> {
+    match map[position] {
+        '[' => map.step(position, Direction::Right),
+        ']' => map.step(position, Direction::Left),
+        _ => None,
+    }
+}
+
+/// Every cell that moves when whatever is at `position` moves one cell in `direction`, in the
+/// order they were found, or `None` when a wall is in the way.
+///
+/// Pushing half of a wide box up or down moves the other half too, so stacked boxes can be pushed
+/// by two boxes at once. Collecting the cells breadth first visits each of them once however they
+/// are stacked.
+fn pushed_cells(map: &Grid<char>, position: Position, direction: Direction) -> Option<Vec<Position>> {
+    let mut cells = vec![position];
+    let mut seen = HashSet::from([position]);
+    let mut next = 0;
+    while let Some(&cell) = cells.get(next) {
+        next += 1;
+        let target = map.step(cell, direction)?;
+        let pushed = match map[target] {
+            '.' => [None, None],
+            'O' => [Some(target), None],
+            '[' | ']' if direction.is_vertical() => [Some(target), partner(map, target)],
+            '[' | ']' => [Some(target), None],
+            _ => return None,
+        };
+        for cell in pushed.into_iter().flatten() {
+            if seen.insert(cell) {
+                cells.push(cell);
+            }
+        }
+    }
+    Some(cells)
+}
+
+/// Moves every one of `cells` one cell in `direction`, leaving empty floor behind them
+fn shift(map: &mut Grid<char>, cells: &[Position], direction: Direction) {
+    let contents = cells.iter().map(|&cell| map[cell]).collect::<Vec<char>>();
+    for &cell in cells {
+        map[cell] = '.';
+    }
+    for (&cell, content) in cells.iter().zip(contents) {
+        let target = map.step(cell, direction).expect("Moved off the map");
+        map[target] = content;
+    }
+}
+
+/// The map after the robot has tried every move
+pub fn simulate(map: &Grid<char>, moves: &[Direction]) -> Grid<char> {
+    let mut map = map.clone();
+    let mut robot = map.find(|&c| c == '@').expect("No robot on the map");
+    for &direction in moves {
+        if let Some(cells) = pushed_cells(&map, robot, direction) {
+            shift(&mut map, &cells, direction);
+            robot = map.step(robot, direction).unwrap();
+        }
+    }
+    debug!("Final map:\n{}", map);
+    map
+}
+
+/// Sum of the GPS coordinates of every box, measured to its left edge
+fn gps_sum(map: &Grid<char>) -> usize {
+    map.indexed_iter()
+        .filter(|(_, &c)| c == 'O' || c == '[')
+        .map(|((row, column), _)| 100 * row + column)
+        .sum()
+}
+
+#[aoc(day15, part1)]
+pub fn part_1(warehouse: &Warehouse) -> Answer {
+    gps_sum(&simulate(&warehouse.map, &warehouse.moves)).into()
+}
+
+#[aoc(day15, part2)]
+pub fn part_2(warehouse: &Warehouse) -> Answer {
+    gps_sum(&simulate(&widen(&warehouse.map), &warehouse.moves)).into()
+}
+
+pub struct Day15;
+
+impl Solver for Day15 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 15;
+    type Input = Warehouse;
+    type Output = Answer;
+
+    fn parse(input: &str) -> Self::Input {
+        input_generator(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Output {
+        part_1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Option<Self::Output> {
+        Some(part_2(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures;
+
+    #[test]
+    fn test_input_generator() {
+        let warehouse = input_generator(&fixtures::load(2024, "day15_2.txt"));
+        assert_eq!(warehouse.map.dim(), (8, 8));
+        assert_eq!(warehouse.map.find(|&c| c == '@'), Some((2, 2)));
+        assert_eq!(warehouse.moves.len(), 15);
+        assert_eq!(warehouse.moves[..3], [Direction::Left, Direction::Up, Direction::Up]);
+
+        let large = input_generator(&fixtures::load(2024, "day15.txt"));
+        assert_eq!(large.moves.len(), 700);
+    }
+
+    #[test]
+    fn test_simulate() {
+        let warehouse = input_generator(&fixtures::load(2024, "day15_2.txt"));
+        let map = simulate(&warehouse.map, &warehouse.moves);
+        assert_eq!(
+            map.to_string(),
+            "########\n#....OO#\n##.....#\n#.....O#\n#.#O@..#\n#...O..#\n#...O..#\n########"
+        );
+    }
+
+    #[test]
+    fn test_simulate_wide() {
+        let warehouse = input_generator(&fixtures::load(2024, "day15_3.txt"));
+        let map = simulate(&widen(&warehouse.map), &warehouse.moves);
+        assert_eq!(
+            map.to_string(),
+            "##############\n##...[].##..##\n##...@.[]...##\n##....[]....##\n##..........##\n##..........##\n##############"
+        );
+        assert_eq!(gps_sum(&map), 618);
+    }
+
+    #[test]
+    fn test_vertical_push_blocked() {
+        // The right box is stopped by the wall, so neither box nor the robot moves
+        let map = Grid::parse("#####\n#...#\n#[]##\n#.[]#\n#..@#\n#####");
+        assert_eq!(pushed_cells(&map, (4, 3), Direction::Up), None);
+        assert_eq!(simulate(&map, &[Direction::Up]), map);
+
+        // With room above, both boxes go up together
+        let map = Grid::parse("#####\n#...#\n#[].#\n#.[]#\n#..@#\n#####");
+        let moved = simulate(&map, &[Direction::Left, Direction::Up, Direction::Up]);
+        assert_eq!(moved.to_string(), "#####\n#[].#\n#.[]#\n#.@.#\n#...#\n#####");
+    }
+
+    #[test]
+    fn test_vertical_push_pyramid() {
+        // An upside down pyramid of wide boxes, each pushing the two resting on it, so the boxes
+        // near the top are reached by a great many different chains of pushes from the robot
+        let height = 30;
+        let wall = "#".repeat(2 * height + 4);
+        let empty = format!("##{}##", ".".repeat(2 * height));
+        let robot = format!("##{}@{}##", ".".repeat(height - 1), ".".repeat(height));
+        let pyramid = (0..height)
+            .map(|i| format!("##{}{}{}##", ".".repeat(i), "[]".repeat(height - i), ".".repeat(i)))
+            .collect::<Vec<String>>();
+
+        let pyramid = pyramid.join("\n");
+        let map = Grid::parse(&format!("{wall}\n{empty}\n{pyramid}\n{robot}\n{wall}"));
+
+        let moved = simulate(&map, &[Direction::Up]);
+        assert_eq!(moved.to_string(), format!("{wall}\n{pyramid}\n{robot}\n{empty}\n{wall}"));
+    }
+
+    #[test]
+    fn test_widen() {
+        let map = Grid::parse("#O.\n.@#");
+        assert_eq!(widen(&map).to_string(), "##[]..\n..@.##");
+    }
+
+    #[test]
+    fn test_part_1() {
+        let warehouse = input_generator(&fixtures::load(2024, "day15_2.txt"));
+        assert_eq!(part_1(&warehouse), 2028);
+
+        let warehouse = input_generator(&fixtures::load(2024, "day15.txt"));
+        assert_eq!(part_1(&warehouse), 10092);
+    }
+
+    #[test]
+    fn test_part_2() {
+        let warehouse = input_generator(&fixtures::load(2024, "day15.txt"));
+        assert_eq!(part_2(&warehouse), 9021);
+    }
+}
@@ -0,0 +1,168 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet};
+use tracing::debug;
+use crate::answer::Answer;
+use crate::grid::{Direction, Grid, Position};
+use crate::solver::Solver;
+
+const MOVE_COST: usize = 1;
+const TURN_COST: usize = 1000;
+
+/// Where the reindeer is and which way it is facing
+type State = (Position, Direction);
+
+/// The best score through the maze and every tile on a path with that score
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Routes {
+    pub score: usize,
+    pub tiles: HashSet<Position>,
+}
+
+#[aoc_generator(day16)]
+pub fn input_generator(input: &str) -> Grid<char> {
+    Grid::parse(input)
+}
+
+/// States reachable in one action from `state`, with what each action costs
+fn actions(maze: &Grid<char>, (position, direction): State) -> impl Iterator<Item = (State, usize)> {
+    let forward = maze
+        .step(position, direction)
+        .filter(|&next| maze[next] != '#')
+        .map(|next| ((next, direction), MOVE_COST));
+    let turns = [direction.turn_left(), direction.turn_right()].map(|turned| ((position, turned), TURN_COST));
+    forward.into_iter().chain(turns)
+}
+
+/// Dijkstra over positions and headings, starting on `S` facing east. Every state remembers all
+/// the states it can be reached from at its lowest cost, so walking those back from the end finds
+/// every best path rather than only one.
+pub fn best_routes(maze: &Grid<char>) -> Option<Routes> {
+    let start = maze.find(|&c| c == 'S').expect("No start in the maze");
+    let end = maze.find(|&c| c == 'E').expect("No end in the maze");
+
+    let mut costs: HashMap<State, usize> = HashMap::from([((start, Direction::Right), 0)]);
+    let mut predecessors: HashMap<State, Vec<State>> = HashMap::new();
+    let mut queue = BinaryHeap::from([Reverse((0, start, Direction::Right))]);
+    let mut score = None;
+    while let Some(Reverse((cost, position, direction))) = queue.pop() {
+        let state = (position, direction);
+        if costs[&state] < cost {
+            continue;
+        }
+        // Everything left in the queue costs at least this much, so no better path can follow
+        if score.is_some_and(|score| cost > score) {
+            break;
+        }
+        if position == end {
+            score = Some(cost);
+            continue;
+        }
+        for (next, action_cost) in actions(maze, state) {
+            let next_cost = cost + action_cost;
+            match costs.get(&next) {
+                Some(&known) if known < next_cost => {}
+                Some(&known) if known == next_cost => predecessors.entry(next).or_default().push(state),
+                _ => {
+                    costs.insert(next, next_cost);
+                    predecessors.insert(next, vec![state]);
+                    queue.push(Reverse((next_cost, next.0, next.1)));
+                }
+            }
+        }
+    }
+    let score = score?;
+    debug!("Best score {} after settling {} states", score, costs.len());
+
+    let mut stack = Direction::ALL
+        .map(|direction| (end, direction))
+        .into_iter()
+        .filter(|state| costs.get(state) == Some(&score))
+        .collect::<Vec<_>>();
+    let mut seen = stack.iter().copied().collect::<HashSet<_>>();
+    while let Some(state) = stack.pop() {
+        for &previous in predecessors.get(&state).into_iter().flatten() {
+            if seen.insert(previous) {
+                stack.push(previous);
+            }
+        }
+    }
+    let tiles = seen.into_iter().map(|(position, _)| position).collect();
+    Some(Routes { score, tiles })
+}
+
+#[aoc(day16, part1)]
+pub fn part_1(maze: &Grid<char>) -> Answer {
+    best_routes(maze).expect("No way through the maze").score.into()
+}
+
+#[aoc(day16, part2)]
+pub fn part_2(maze: &Grid<char>) -> Answer {
+    best_routes(maze).expect("No way through the maze").tiles.len().into()
+}
+
+pub struct Day16;
+
+impl Solver for Day16 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 16;
+    type Input = Grid<char>;
+    type Output = Answer;
+
+    fn parse(input: &str) -> Self::Input {
+        input_generator(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Output {
+        part_1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Option<Self::Output> {
+        Some(part_2(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures;
+
+    #[test]
+    fn test_best_routes() {
+        // Straight along the corridor, then one turn up
+        let maze = Grid::parse("#####\n#..E#\n#S..#\n#####");
+        let routes = best_routes(&maze).unwrap();
+        assert_eq!(routes.score, 1003);
+        assert_eq!(routes.tiles, HashSet::from([(2, 1), (2, 2), (2, 3), (1, 3)]));
+
+        // Going round the wall above or below costs the same, so both sides are on a best path
+        let maze = Grid::parse("#######\n#.....#\n#S###E#\n#.....#\n#######");
+        let routes = best_routes(&maze).unwrap();
+        assert_eq!(routes.score, 3006);
+        assert_eq!(routes.tiles.len(), 12);
+
+        // Facing away from the end takes two turns before moving
+        let maze = Grid::parse("#####\n#E.S#\n#####");
+        assert_eq!(best_routes(&maze).unwrap().score, 2002);
+
+        let maze = Grid::parse("#####\n#S#E#\n#####");
+        assert_eq!(best_routes(&maze), None);
+    }
+
+    #[test]
+    fn test_part_1() {
+        let maze = input_generator(&fixtures::load(2024, "day16.txt"));
+        assert_eq!(part_1(&maze), 7036);
+
+        let maze = input_generator(&fixtures::load(2024, "day16_2.txt"));
+        assert_eq!(part_1(&maze), 11048);
+    }
+
+    #[test]
+    fn test_part_2() {
+        let maze = input_generator(&fixtures::load(2024, "day16.txt"));
+        assert_eq!(part_2(&maze), 45);
+
+        let maze = input_generator(&fixtures::load(2024, "day16_2.txt"));
+        assert_eq!(part_2(&maze), 64);
+    }
+}
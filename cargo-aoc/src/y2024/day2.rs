@@ -1,6 +1,24 @@
+use crate::answer::Answer;
+use crate::parse::Parser;
+use crate::solver::Solver;
+
 #[aoc_generator(day2)]
 pub fn input_generator(input: &str) -> Vec<Vec<i32>> {
-    input.lines().map(|x| x.split(' ').map(|t| t.parse().unwrap()).collect()).collect()
+    let mut parser = Parser::new(input);
+    let mut reports = Vec::new();
+    while !parser.is_empty() {
+        let mut report = Vec::with_capacity(8);
+        while let Some(level) = parser.signed() {
+            report.push(level);
+            parser.skip_spaces();
+        }
+        if !parser.at_line_end() {
+            panic!("Unexpected {:?} in report {}", parser.peek().map(char::from), reports.len() + 1);
+        }
+        parser.newline();
+        reports.push(report);
+    }
+    reports
 }
 
 fn is_safe(report: &[i32]) -> bool {
@@ -9,8 +27,8 @@ fn is_safe(report: &[i32]) -> bool {
 }
 
 #[aoc(day2, part1)]
-fn part_1(input: &Vec<Vec<i32>>) -> usize {
-    input.iter().map(|row| is_safe(row)).filter(|&b| b).count()
+pub fn part_1(input: &[Vec<i32>]) -> Answer {
+    input.iter().map(|row| is_safe(row)).filter(|&b| b).count().into()
 }
 
 fn and(a: &[bool], b: &[bool]) -> Vec<bool> {
@@ -18,7 +36,7 @@ fn and(a: &[bool], b: &[bool]) -> Vec<bool> {
 }
 
 #[aoc(day2, part2)]
-fn part2(input: &Vec<Vec<i32>>) -> usize {
+pub fn part2(input: &[Vec<i32>]) -> Answer {
     fn is_safe_damp(report: &[i32]) -> bool {
         let increasing = report
             .windows(2)
@@ -62,15 +80,36 @@ fn part2(input: &Vec<Vec<i32>>) -> usize {
         .map(|row| is_safe_damp(row))
         .filter(|&b| b)
         .count();
-    result
+    result.into()
 }
 
+pub struct Day2;
+
+impl Solver for Day2 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 2;
+    type Input = Vec<Vec<i32>>;
+    type Output = Answer;
+
+    fn parse(input: &str) -> Self::Input {
+        input_generator(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Output {
+        part_1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Option<Self::Output> {
+        Some(part2(input))
+    }
+}
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
+    #[allow(clippy::needless_borrow)]
     fn test_input_generator() {
         let input = "1 2\n7 8 9";
 
@@ -87,6 +126,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::useless_vec)]
     fn test_is_safe() {
 
         let input = vec![
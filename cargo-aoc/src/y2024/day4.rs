@@ -0,0 +1,165 @@
+use ndarray::Array2;
+use aoc_generators::processors::board_generator::generate_2d_board_char;
+use crate::answer::Answer;
+use crate::solver::Solver;
+
+#[aoc_generator(day4)]
+pub fn input_generator(input: &str) -> Array2<char> {
+    generate_2d_board_char(input)
+}
+
+
+#[aoc(day4, part1)]
+pub fn part_1(board: &Array2<char>) -> Answer {
+    find_word_matches("XMAS", board).len().into()
+}
+
+/// Cells of a single match, in the order the word's letters appear in them
+pub type Match = Vec<(usize, usize)>;
+
+/// Offsets walked along by the linear search. Every axis is also searched backwards by matching
+/// the reversed word.
+const AXES: [(isize, isize); 4] = [(0, 1), (1, 0), (1, 1), (1, -1)];
+
+/// Every occurrence of `word` along rows, columns and diagonals, forwards or backwards
+pub fn find_word_matches(word: &str, board: &Array2<char>) -> Vec<Match> {
+    let word = word.chars().collect::<Vec<char>>();
+    let reversed = word.iter().rev().copied().collect::<Vec<char>>();
+
+    let mut matches = Vec::new();
+    for ((row, column), _) in board.indexed_iter() {
+        for (row_step, column_step) in AXES {
+            let cells = (0..word.len())
+                .map(|i| {
+                    let row = row.checked_add_signed(row_step * i as isize)?;
+                    let column = column.checked_add_signed(column_step * i as isize)?;
+                    board.get((row, column)).map(|_| (row, column))
+                })
+                .collect::<Option<Match>>();
+            let Some(cells) = cells else {
+                continue;
+            };
+
+            let letters = cells.iter().map(|&cell| board[cell]).collect::<Vec<char>>();
+            if letters == word {
+                matches.push(cells);
+            } else if letters == reversed {
+                matches.push(cells.into_iter().rev().collect());
+            }
+        }
+    }
+    matches
+}
+
+/// Every X of two diagonal `word`s crossing at their middle letters, each read either way. The
+/// cells are those of the falling diagonal followed by the rising one, without repeating the middle.
+pub fn find_x_matches(word: &str, board: &Array2<char>) -> Vec<Match> {
+    let word = word.chars().collect::<Vec<char>>();
+    let size = word.len();
+    let reads_word = |cells: &[(usize, usize)]| {
+        let letters = cells.iter().map(|&cell| board[cell]);
+        letters.clone().eq(word.iter().copied()) || letters.rev().eq(word.iter().copied())
+    };
+
+    let (rows, columns) = board.dim();
+    let mut matches = Vec::new();
+    for row in 0..(rows + 1).saturating_sub(size) {
+        for column in 0..(columns + 1).saturating_sub(size) {
+            let falling = (0..size).map(|i| (row + i, column + i)).collect::<Match>();
+            let rising = (0..size).map(|i| (row + size - 1 - i, column + i)).collect::<Match>();
+            if reads_word(&falling) && reads_word(&rising) {
+                let middle = falling[size / 2];
+                matches.push(falling.into_iter().chain(rising.into_iter().filter(|&cell| cell != middle)).collect());
+            }
+        }
+    }
+    matches
+}
+
+/// The board with every letter that is not part of a match replaced by `.`
+pub fn render_matches(board: &Array2<char>, matches: &[Match]) -> Array2<char> {
+    let mut rendered = Array2::from_elem(board.dim(), '.');
+    for &cell in matches.iter().flatten() {
+        rendered[cell] = board[cell];
+    }
+    rendered
+}
+
+#[aoc(day4, part2)]
+pub fn part_2(board: &Array2<char>) -> Answer {
+    find_x_matches("MAS", board).len().into()
+}
+
+pub struct Day4;
+
+impl Solver for Day4 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 4;
+    type Input = Array2<char>;
+    type Output = Answer;
+
+    fn parse(input: &str) -> Self::Input {
+        input_generator(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Output {
+        part_1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Option<Self::Output> {
+        Some(part_2(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::fixtures;
+    use super::*;
+
+    #[test]
+    fn test_find_word_matches_by_axis() {
+        for (fixture, expected) in [("day4_rows.txt", 4), ("day4_cols.txt", 5), ("day4_diag.txt", 4)] {
+            let board = input_generator(fixtures::load(2024, fixture).as_str());
+            assert_eq!(find_word_matches("XMAS", &board).len(), expected, "{}", fixture);
+        }
+    }
+
+    #[test]
+    fn test_find_word_matches() {
+        let board = input_generator(fixtures::load(2024, "day4.txt").as_str());
+        let matches = find_word_matches("XMAS", &board);
+        assert_eq!(matches.len(), 18);
+        // Backwards matches list their cells from the X
+        assert!(matches.contains(&vec![(1, 4), (1, 3), (1, 2), (1, 1)]));
+
+        let rendered = Answer::grid(&render_matches(&board, &matches));
+        assert_eq!(rendered, fixtures::load(2024, "day4_matches.txt").as_str());
+    }
+
+    #[test]
+    fn test_find_x_matches() {
+        let board = input_generator(fixtures::load(2024, "day4.txt").as_str());
+        let matches = find_x_matches("MAS", &board);
+        assert_eq!(matches.len(), 9);
+        assert!(matches.iter().all(|cells| cells.len() == 5));
+        assert_eq!(matches[0], vec![(0, 1), (1, 2), (2, 3), (2, 1), (0, 3)]);
+
+        let rendered = Answer::grid(&render_matches(&board, &matches));
+        assert_eq!(rendered, fixtures::load(2024, "day4_x_matches.txt").as_str());
+    }
+
+    #[test]
+    fn test_part_1() {
+        let board = input_generator(fixtures::load(2024, "day4.txt").as_str());
+        let result = part_1(&board);
+        assert_eq!(result, 18);
+    }
+
+    #[test]
+    fn test_part_2() {
+        let board = input_generator(fixtures::load(2024, "day4.txt").as_str());
+        let result = part_2(&board);
+        assert_eq!(result, 9);
+    }
+
+}
\ No newline at end of file
@@ -1,6 +1,10 @@
 use std::cmp::Ordering;
 use std::collections::HashMap;
 use aoc_collections::search::{find_middle_element, FindMiddleElementMode};
+use tracing::debug;
+use crate::answer::Answer;
+use crate::parse::Parser;
+use crate::solver::Solver;
 
 struct SafetyPageOrderer {
     page_mappings: HashMap<u32, Vec<u32>>
@@ -27,7 +31,7 @@ impl SafetyPageOrderer {
         Ordering::Equal
     }
 
-    pub fn is_ordered(&self, pages: &Vec<u32>) -> bool {
+    pub fn is_ordered(&self, pages: &[u32]) -> bool {
         
         for i in 0..pages.len() {
             for j in i+1..pages.len() {
@@ -41,93 +45,108 @@ impl SafetyPageOrderer {
 }
 
 #[aoc_generator(day5)]
-fn input_generator_day5(input: &str) -> (HashMap<u32, Vec<u32>>, Vec<Vec<u32>>) {
-    let (page_order, page_sequence) = split_input_into_sections(input);
-
-    let order_map = generate_order_hashmap(page_order);
-
-    let processed_page_sequence = page_sequence.iter().map(|line| { line.split(",").map(|s| { s.parse::<u32>().unwrap() }).collect() }).collect();
-    (order_map, processed_page_sequence)
+pub fn input_generator_day5(input: &str) -> (HashMap<u32, Vec<u32>>, Vec<Vec<u32>>) {
+    let mut parser = Parser::new(input);
+
+    // Our first section lists the ordering, ended by a blank line
+    let order_map = generate_order_hashmap(std::iter::from_fn(|| order_rule(&mut parser)));
+    parser.newline();
+
+    let mut page_sequence = Vec::new();
+    while !parser.is_empty() {
+        let mut pages = Vec::new();
+        loop {
+            pages.push(parser.unsigned().expect("Expected a page number"));
+            if !parser.skip(b',') {
+                break;
+            }
+        }
+        page_sequence.push(pages);
+        // Blank lines after the last update are ignored
+        while parser.newline() {}
+    }
+    (order_map, page_sequence)
 }
 
-fn generate_order_hashmap(page_order: Vec<&str>) -> HashMap<u32, Vec<u32>> {
-    let mut order_map: HashMap<u32, Vec<u32>> = HashMap::new();
-
-    // Our first section lists the ordering so we process that into a map to allow lookup
-    page_order.iter().map(|order: &&str| {
-        let s = order.split_once("|");
-        if let Some((a, b)) = s {
-            let a = a.parse::<u32>().unwrap();
-            let b = b.parse::<u32>().unwrap();
-            (a, b)
-        } else {
-            panic!("Cannot split order string on '|'");
-        }
-    }).for_each(|order| {
-        let this = order.0;
-        // this has to be before the 1 value
-        let after = order.1;
-
-        if let Some(order_vec) = order_map.get_mut(&this) {
-            order_vec.push(after);
-        } else {
-            let order_vec = vec![after];
-            order_map.insert(this, order_vec);
+/// Parses an `a|b` line, `None` at the blank line ending the ordering section
+fn order_rule(parser: &mut Parser) -> Option<(u32, u32)> {
+    if parser.at_line_end() {
+        return None;
+    }
+    let before = parser.unsigned();
+    let separated = parser.skip(b'|');
+    match (before, separated, parser.unsigned()) {
+        (Some(before), true, Some(after)) if parser.newline() || parser.is_empty() => {
+            Some((before, after))
         }
-    });
-
-    order_map
+        _ => panic!("Cannot split order string on '|'"),
+    }
 }
 
+fn generate_order_hashmap(page_order: impl IntoIterator<Item = (u32, u32)>) -> HashMap<u32, Vec<u32>> {
+    let mut order_map: HashMap<u32, Vec<u32>> = HashMap::new();
 
-fn split_input_into_sections(input: &str) -> (Vec<&str>, Vec<&str>) {
-    let mut section_1 = vec![];
-    let mut section_2 = vec![];
-
-    let mut workind = &mut section_1;
-    for line in input.lines() {
-        if line.is_empty() {
-            workind = &mut section_2;
-        } else {
-            workind.push(line);
-        }
+    // Process the ordering into a map to allow lookup, the first value has to be before the second
+    for (this, after) in page_order {
+        order_map.entry(this).or_default().push(after);
     }
-    (section_1, section_2)
+
+    order_map
 }
 
 #[aoc(day5, part1)]
-fn part1(input: &(HashMap<u32, Vec<u32>>, Vec<Vec<u32>>)) -> u32 {
+pub fn part1(input: &(HashMap<u32, Vec<u32>>, Vec<Vec<u32>>)) -> Answer {
     let (order_map, page_sequence) = input;
     let orderer = SafetyPageOrderer::new(order_map.clone());
 
-    page_sequence.iter().filter(|page| { orderer.is_ordered(page) }).map(|s| find_middle_element(s, FindMiddleElementMode::Error).unwrap()).sum()
+    page_sequence.iter().filter(|page| { orderer.is_ordered(page) }).map(|s| find_middle_element(s, FindMiddleElementMode::Error).unwrap()).sum::<u32>().into()
 
 }
 
 #[aoc(day5, part2)]
-fn part2(input: &(HashMap<u32, Vec<u32>>, Vec<Vec<u32>>)) -> u32 {
+pub fn part2(input: &(HashMap<u32, Vec<u32>>, Vec<Vec<u32>>)) -> Answer {
     let (order_map, page_sequence) = input;
     let orderer = SafetyPageOrderer::new(order_map.clone());
 
     page_sequence.iter().filter(|page| { !orderer.is_ordered(page) }).map(|page| {
-        println!("Unordered page: {:?}", page);
+        debug!("Unordered page: {:?}", page);
         let mut sorted_page = page.clone();
         sorted_page.sort_by(|a, b| { orderer.cmp(*a, *b)});
-        println!("Sorted page: {:?}", sorted_page);
-        let middle_element = find_middle_element(&sorted_page, FindMiddleElementMode::Error).unwrap().clone();
-        middle_element
-    }).sum()
+        debug!("Sorted page: {:?}", sorted_page);
+        *find_middle_element(&sorted_page, FindMiddleElementMode::Error).unwrap()
+    }).sum::<u32>().into()
+}
+
+pub struct Day5;
+
+impl Solver for Day5 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 5;
+    type Input = (HashMap<u32, Vec<u32>>, Vec<Vec<u32>>);
+    type Output = Answer;
+
+    fn parse(input: &str) -> Self::Input {
+        input_generator_day5(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Output {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Option<Self::Output> {
+        Some(part2(input))
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use std::fs::read_to_string;
+    use crate::fixtures;
     use super::*;
 
     #[test]
     fn test_part2() {
 
-        let input = read_to_string("../../test_input/2024/day5.txt").unwrap();
+        let input = fixtures::load(2024, "day5.txt");
         let (order_map, page_sequence) = input_generator_day5(&input);
 
         let result = part2(&(order_map, page_sequence));
@@ -138,7 +157,7 @@ mod tests {
     #[test]
     fn test_part1() {
 
-        let input = read_to_string("../../test_input/2024/day5.txt").unwrap();
+        let input = fixtures::load(2024, "day5.txt");
         let (order_map, page_sequence) = input_generator_day5(&input);
 
         let result = part1(&(order_map, page_sequence));
@@ -191,9 +210,15 @@ mod tests {
         assert_eq!(order_map, expected_order_map);
         assert_eq!(processed_page_sequence, expected_page_sequence);
     }
+
+    #[test]
+    fn test_input_generator_day5_trailing_blank_lines() {
+        let (_, page_sequence) = input_generator_day5("1|2\n\n1,2\n3,4\n\n\n");
+        assert_eq!(page_sequence, vec![vec![1, 2], vec![3, 4]]);
+    }
     #[test]
     fn test_generate_order_hashmap() {
-        let page_order = vec!["1|2", "2|3", "1|3", "3|4"];
+        let page_order = vec![(1, 2), (2, 3), (1, 3), (3, 4)];
         let expected_map = {
             let mut map = HashMap::new();
             map.insert(1, vec![2]);
@@ -211,14 +236,12 @@ mod tests {
     #[test]
     #[should_panic(expected = "Cannot split order string on '|'")]
     fn test_generate_order_hashmap_panic() {
-        let page_order = vec!["1|2", "invalid", "3|4"];
-        generate_order_hashmap(page_order);
+        input_generator_day5("1|2\ninvalid\n3|4\n\n1,2");
     }
     #[test]
-    fn test_split_input_into_sections() {
-        let input = "a\nb\n\nc\nd\n";
-        let (first, second) = split_input_into_sections(input);
-        assert_eq!(first, vec!["a", "b"]);
-        assert_eq!(second, vec!["c", "d"]);
+    fn test_input_generator_day5_line_endings() {
+        let (order_map, page_sequence) = input_generator_day5("1|2\r\n2|3\r\n\r\n1,2,3\r\n3\r\n");
+        assert_eq!(order_map.len(), 2);
+        assert_eq!(page_sequence, vec![vec![1, 2, 3], vec![3]]);
     }
 }
\ No newline at end of file
@@ -0,0 +1,205 @@
+//! State behind the interactive day 6 debugger: the guard's walk over a map the user can add
+//! obstructions to, and a position within that walk that can be moved a turn at a time.
+//!
+//! Drawing and key handling live in the `aoc24 debug` command, this only tracks what to show.
+
+use std::collections::BTreeSet;
+
+use ndarray::Array2;
+
+use super::render::{overlay, turn_ends};
+use super::{Guard, Step};
+use crate::grid::Grid;
+
+/// How the current walk ends
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    /// The guard leaves the map after visiting this many cells
+    Leaves(usize),
+    Loops,
+}
+
+#[derive(Debug, Clone)]
+pub struct Debugger {
+    board: Grid<char>,
+    start: Guard,
+    obstructions: BTreeSet<(usize, usize)>,
+    steps: Vec<Step>,
+    /// Steps walked after each turn, see [`turn_ends`]
+    ends: Vec<usize>,
+    outcome: Outcome,
+    /// Index into `ends` of the turn shown
+    turn: usize,
+    cursor: (usize, usize),
+    pub show_visited: bool,
+}
+
+impl Debugger {
+    pub fn new(board: Grid<char>, guard: Guard) -> Self {
+        let cursor = (guard.starting_row, guard.starting_column);
+        let mut debugger = Self {
+            board,
+            start: guard,
+            obstructions: BTreeSet::new(),
+            steps: Vec::new(),
+            ends: Vec::new(),
+            outcome: Outcome::Loops,
+            turn: 0,
+            cursor,
+            show_visited: true,
+        };
+        debugger.simulate();
+        debugger
+    }
+
+    /// Walks the guard again over the map with the current obstructions, keeping the shown turn
+    /// where possible
+    fn simulate(&mut self) {
+        let mut board = self.board.clone();
+        for &position in &self.obstructions {
+            board[position] = '#';
+        }
+        let mut guard = self.start.clone();
+        self.outcome = if guard.walk(&board) {
+            Outcome::Loops
+        } else {
+            Outcome::Leaves(guard.visited_count())
+        };
+        self.steps = guard.steps();
+        self.ends = turn_ends(&self.steps);
+        self.turn = self.turn.min(self.last_turn());
+    }
+
+    pub fn outcome(&self) -> Outcome {
+        self.outcome
+    }
+
+    pub fn turn(&self) -> usize {
+        self.turn
+    }
+
+    /// Index of the final position of the walk
+    pub fn last_turn(&self) -> usize {
+        self.ends.len() - 1
+    }
+
+    /// Where the guard stands at the shown turn, `None` before it has moved
+    pub fn guard(&self) -> Option<Step> {
+        self.steps[..self.ends[self.turn]].last().copied()
+    }
+
+    pub fn next(&mut self) {
+        self.jump(self.turn + 1);
+    }
+
+    pub fn previous(&mut self) {
+        self.jump(self.turn.saturating_sub(1));
+    }
+
+    /// Shows the given turn, or the end of the walk when it has fewer turns
+    pub fn jump(&mut self, turn: usize) {
+        self.turn = turn.min(self.last_turn());
+    }
+
+    pub fn cursor(&self) -> (usize, usize) {
+        self.cursor
+    }
+
+    /// Moves the cursor by the given offsets, staying on the map
+    pub fn move_cursor(&mut self, rows: isize, columns: isize) {
+        let (height, width) = self.board.dim();
+        let (row, column) = self.cursor;
+        self.cursor = (
+            row.saturating_add_signed(rows).min(height - 1),
+            column.saturating_add_signed(columns).min(width - 1),
+        );
+    }
+
+    pub fn obstructions(&self) -> &BTreeSet<(usize, usize)> {
+        &self.obstructions
+    }
+
+    /// Adds an obstruction under the cursor or removes the one already there, then walks the guard
+    /// again. Returns false when the cell cannot hold one, as it is a wall or the guard's start.
+    pub fn toggle_obstruction(&mut self) -> bool {
+        if !self.obstructions.remove(&self.cursor) {
+            if self.board[self.cursor] != '.' {
+                return false;
+            }
+            self.obstructions.insert(self.cursor);
+        }
+        self.simulate();
+        true
+    }
+
+    /// The map with the walk up to the shown turn drawn on it and added obstructions as `O`
+    pub fn grid(&self) -> Array2<char> {
+        let mut grid = overlay(&self.board, &self.steps[..self.ends[self.turn]], None);
+        for &position in &self.obstructions {
+            grid[position] = 'O';
+        }
+        grid
+    }
+
+    /// Cells walked through up to the shown turn
+    pub fn visited(&self) -> Array2<bool> {
+        let mut visited = Array2::from_elem(self.board.dim(), false);
+        for step in &self.steps[..self.ends[self.turn]] {
+            visited[(step.row, step.column)] = true;
+        }
+        visited
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures;
+    use crate::y2024::day6::{input_generator_day6, Direction};
+
+    fn debugger() -> Debugger {
+        let (board, guard) = input_generator_day6(&fixtures::load(2024, "day6/day6.txt"));
+        Debugger::new(board, guard)
+    }
+
+    #[test]
+    fn test_stepping() {
+        let mut debugger = debugger();
+        assert_eq!(debugger.outcome(), Outcome::Leaves(41));
+        assert_eq!(debugger.guard(), None);
+        assert_eq!(debugger.visited().iter().filter(|&&v| v).count(), 0);
+
+        debugger.next();
+        let first = Step { row: 1, column: 4, direction: Direction::Right };
+        assert_eq!(debugger.guard(), Some(first));
+        assert_eq!(debugger.grid()[(3, 4)], '|');
+
+        debugger.jump(1000);
+        assert_eq!(debugger.turn(), debugger.last_turn());
+        assert_eq!(debugger.visited().iter().filter(|&&v| v).count(), 41);
+        debugger.next();
+        assert_eq!(debugger.turn(), debugger.last_turn());
+
+        debugger.jump(0);
+        debugger.previous();
+        assert_eq!(debugger.turn(), 0);
+    }
+
+    #[test]
+    fn test_toggle_obstruction() {
+        let mut debugger = debugger();
+        assert!(!debugger.toggle_obstruction(), "Obstruction placed on the guard");
+
+        debugger.move_cursor(0, -1);
+        assert!(debugger.toggle_obstruction());
+        assert_eq!(debugger.outcome(), Outcome::Loops);
+        assert_eq!(debugger.grid()[(6, 3)], 'O');
+
+        assert!(debugger.toggle_obstruction());
+        assert!(debugger.obstructions().is_empty());
+        assert_eq!(debugger.outcome(), Outcome::Leaves(41));
+
+        debugger.move_cursor(-100, 100);
+        assert_eq!(debugger.cursor(), (0, 9));
+    }
+}
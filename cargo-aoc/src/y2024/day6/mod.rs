@@ -0,0 +1,414 @@
+use std::clone::Clone;
+use std::collections::HashSet;
+use std::fmt::{ Display, Formatter};
+use tracing::debug;
+use crate::answer::Answer;
+use crate::grid::Grid;
+pub use crate::grid::Direction;
+use crate::solver::Solver;
+
+pub mod debugger;
+pub mod render;
+
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct GuardTurn {
+    direction: Direction,
+    row: usize,
+    column: usize,
+}
+
+/// A single cell of the guard's walk
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Step {
+    pub row: usize,
+    pub column: usize,
+    pub direction: Direction,
+}
+
+#[derive(Debug, Clone)]
+pub struct Guard {
+    starting_row: usize,
+    starting_column: usize,
+    starting_direction: Direction,
+    turns: Vec<GuardTurn>,
+    travelled_distances: Vec<usize>,
+    visited_cells: HashSet<(usize, usize)>,
+    left_maze: bool
+}
+
+impl Display for Guard {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Starting Row: {}, Starting Column: {}, Starting Direction: {:?}, Turns: {:?}", self.starting_row, self.starting_column, self.starting_direction, self.turns)
+    }
+}
+
+impl Guard {
+    pub fn new(starting_row: usize, starting_column: usize, starting_direction: Direction) -> Guard {
+        let mut g = Guard {
+            starting_row,
+            starting_column,
+            starting_direction,
+            turns: Vec::new(),
+            travelled_distances: Vec::new(),
+            visited_cells: HashSet::new(),
+            left_maze: false
+        };
+
+        g.visited_cells.insert((starting_row, starting_column));
+        g
+    }
+
+    pub fn get_current_state(&self) -> Option<(&Direction, usize, usize)> {
+
+        if self.left_maze {
+            None
+        } else {
+            if self.turns.is_empty() {
+                Some((&self.starting_direction, self.starting_row, self.starting_column))
+            } else {
+                let last_turn = &self.turns.last().unwrap();
+                Some((&last_turn.direction, last_turn.row, last_turn.column))
+            }
+        }
+    }
+
+    /// Walks straight ahead until the next obstruction, turning right in front of it, or off the
+    /// map. Returns `None` once the guard has left the map.
+    pub fn move_to_next_turn(&mut self, board: &Grid<char>) -> Option<()> {
+        let (&direction, row, column) = self.get_current_state()?;
+        let mut position = (row, column);
+        let mut distance = 0;
+        self.visited_cells.insert(position);
+
+        while let Some(next) = board.step(position, direction) {
+            if board[next] == '#' {
+                let (row, column) = position;
+                self.turns.push(GuardTurn { direction: direction.turn_right(), row, column });
+                self.travelled_distances.push(distance);
+                return Some(());
+            }
+            position = next;
+            distance += 1;
+            self.visited_cells.insert(position);
+        }
+        // If we get here we've not hit an obstacle so we leave the board
+        debug!("Leaving the board at {:?}", position);
+        self.travelled_distances.push(distance);
+        None
+    }
+
+    /// Number of distinct cells walked through so far
+    pub fn visited_count(&self) -> usize {
+        self.visited_cells.len()
+    }
+
+    /// True once the guard has turned at the same place in the same direction twice, as it will
+    /// then keep walking the same loop forever
+    pub fn is_looping(&self) -> bool {
+        match self.turns.split_last() {
+            Some((last, earlier)) => earlier.contains(last),
+            None => false,
+        }
+    }
+
+    /// Every cell walked through so far, in order, with the direction the guard was walking in.
+    /// Cells where the guard turned appear twice, once for each direction.
+    pub fn steps(&self) -> Vec<Step> {
+        let mut steps = Vec::new();
+        let (mut direction, mut row, mut column) =
+            (self.starting_direction, self.starting_row, self.starting_column);
+
+        for (i, &distance) in self.travelled_distances.iter().enumerate() {
+            let (row_step, column_step) = direction.offset();
+            for j in 0..=distance as isize {
+                let row = row.checked_add_signed(row_step * j).expect("Walked off the top of the map");
+                let column = column.checked_add_signed(column_step * j).expect("Walked off the left of the map");
+                steps.push(Step { row, column, direction });
+            }
+
+            match self.turns.get(i) {
+                Some(turn) => (direction, row, column) = (turn.direction, turn.row, turn.column),
+                None => break,
+            }
+        }
+        steps
+    }
+
+    /// Walks until the guard leaves the map or starts repeating a loop, returning whether it loops.
+    /// Unlike [`Guard::patrol`] this always finishes.
+    pub fn walk(&mut self, board: &Grid<char>) -> bool {
+        while !self.is_looping() && self.move_to_next_turn(board).is_some() {}
+        self.is_looping()
+    }
+
+    pub fn patrol(&mut self, board: &Grid<char>) -> usize {
+        while self.move_to_next_turn(board).is_some() {
+            debug!("Distance: {}, Last turn: {:?}", self.travelled_distances.last().unwrap(), self.turns.last().unwrap());
+            debug!("{:?}", self.visited_cells.len());
+        }
+
+        debug!("Cells Visited: {:?}, Total: {}", self.visited_cells, self.visited_cells.len());
+        self.visited_cells.len()
+    }
+
+}
+
+#[aoc_generator(day6)]
+pub fn input_generator_day6(input: &str) -> (Grid<char>, Guard) {
+
+    let board = Grid::parse(input);
+
+    if let Some((row, column)) = board.find(|&cell| Direction::from_char(cell).is_some()) {
+        let direction = Direction::from_char(board[(row, column)]).unwrap();
+        (board, Guard::new(row, column, direction))
+    } else {
+        panic!("No starting details found");
+    }
+}
+
+#[aoc(day6, part1)]
+pub fn part1(input: &(Grid<char>, Guard)) -> Answer {
+    let (board, guard) = input;
+    debug!("{:?}", board);
+
+    let mut guard = guard.clone();
+    debug!("{:?}", guard);
+
+    guard.patrol(board).into()
+
+}
+
+pub struct Day6;
+
+impl Solver for Day6 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 6;
+    type Input = (Grid<char>, Guard);
+    type Output = Answer;
+
+    fn parse(input: &str) -> Self::Input {
+        input_generator_day6(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Output {
+        part1(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::fixtures;
+    use super::*;
+
+    #[test]
+    fn test_patrol() {
+        let input = fixtures::load(2024, "day6/day6.txt");
+        let (board, guard) = input_generator_day6(&input);
+        println!("{:?}", board);
+        let mut guard = guard.clone();
+        guard.patrol(&board);
+
+        assert_eq!(41, guard.visited_cells.len());
+
+    }
+
+    #[test]
+    fn test_steps() {
+        let input = fixtures::load(2024, "day6/day6.txt");
+        let (board, mut guard) = input_generator_day6(&input);
+        guard.patrol(&board);
+
+        let steps = guard.steps();
+        // Every turn cell is walked twice, once in each direction
+        assert_eq!(steps.len(), guard.travelled_distances.iter().sum::<usize>() + guard.travelled_distances.len());
+        assert_eq!(steps[0], Step { row: 6, column: 4, direction: Direction::Up });
+        assert_eq!(steps[5], Step { row: 1, column: 4, direction: Direction::Up });
+        assert_eq!(steps[6], Step { row: 1, column: 4, direction: Direction::Right });
+        assert_eq!(steps.iter().map(|s| (s.row, s.column)).collect::<HashSet<_>>().len(), 41);
+    }
+
+    #[test]
+    fn test_is_looping() {
+        let input = fixtures::load(2024, "day6/day6.txt");
+        let (mut board, mut guard) = input_generator_day6(&input);
+        // One of the obstructions from the puzzle description that traps the guard
+        board[(6, 3)] = '#';
+
+        assert!(guard.walk(&board));
+        assert!(guard.turns.len() > 4);
+
+        let (board, mut guard) = input_generator_day6(&input);
+        assert!(!guard.walk(&board));
+        assert!(!guard.is_looping());
+    }
+
+    #[test]
+    fn test_part_1_direction_up_obstruction() {
+        let input = fixtures::load(2024, "day6/day6_starting_up_obstruction.txt");
+
+        let (board, guard) = input_generator_day6(&input);
+        println!("{:?}", board);
+        let mut guard = guard.clone();
+        println!("Guard: {}", guard);
+
+        let result = guard.move_to_next_turn(&board);
+
+        assert!(result.is_some());
+
+        let (current_direction, row, column) = guard.get_current_state().unwrap();
+        assert!(matches!(*current_direction, Direction::Right));
+        assert_eq!(row, 1);
+        assert_eq!(*guard.travelled_distances.last().unwrap(), 2usize);
+        assert_eq!(column, guard.starting_column);
+
+    }
+
+    #[test]
+    fn test_part_1_direction_up_no_obstruction() {
+        let input = fixtures::load(2024, "day6/day6_starting_up_no_obstruction.txt");
+
+        let (board, guard) = input_generator_day6(&input);
+        println!("{:?}", board);
+        let mut guard = guard.clone();
+        println!("Guard: {}", guard);
+
+        let result = guard.move_to_next_turn(&board);
+
+        assert!(result.is_none());
+        assert_eq!(*guard.travelled_distances.last().unwrap(), 4usize);
+
+
+    }
+
+    #[test]
+    fn test_part_1_direction_down_obstruction() {
+        let input = fixtures::load(2024, "day6/day6_starting_down_obstruction.txt");
+
+        let (board, guard) = input_generator_day6(&input);
+        println!("{:?}", board);
+        let mut guard = guard.clone();
+        println!("Guard: {}", guard);
+
+        let result = guard.move_to_next_turn(&board);
+
+        assert!(result.is_some());
+
+        let (current_direction, row, column) = guard.get_current_state().unwrap();
+        assert!(matches!(*current_direction, Direction::Left));
+        assert_eq!(row, 2);
+        assert_eq!(*guard.travelled_distances.last().unwrap(), 0usize);
+        assert_eq!(column, guard.starting_column);
+
+    }
+
+    #[test]
+    fn test_part_1_direction_down_no_obstruction() {
+        let input = fixtures::load(2024, "day6/day6_starting_down_no_obstruction.txt");
+
+        let (board, guard) = input_generator_day6(&input);
+        println!("{:?}", board);
+        let mut guard = guard.clone();
+        println!("Guard: {}", guard);
+
+        let result = guard.move_to_next_turn(&board);
+
+        assert!(result.is_none());
+        assert_eq!(*guard.travelled_distances.last().unwrap(), 2usize);
+
+
+    }
+
+    #[test]
+    fn test_part_1_direction_left_obstruction() {
+        let input = fixtures::load(2024, "day6/day6_starting_left_obstruction.txt");
+
+        let (board, guard) = input_generator_day6(&input);
+        println!("{:?}", board);
+        let mut guard = guard.clone();
+        println!("Guard: {}", guard);
+
+        let result = guard.move_to_next_turn(&board);
+
+        assert!(result.is_some());
+
+        let (current_direction, row, column) = guard.get_current_state().unwrap();
+        assert!(matches!(*current_direction, Direction::Up));
+        assert_eq!(row, guard.starting_row);
+        assert_eq!(*guard.travelled_distances.last().unwrap(), 1usize);
+        assert_eq!(column, 2);
+
+    }
+
+    #[test]
+    fn test_part_1_direction_left_no_obstruction() {
+        let input = fixtures::load(2024, "day6/day6_starting_left_no_obstruction.txt");
+
+        let (board, guard) = input_generator_day6(&input);
+        println!("{:?}", board);
+        let mut guard = guard.clone();
+        println!("Guard: {}", guard);
+
+        let result = guard.move_to_next_turn(&board);
+
+        assert!(result.is_none());
+        assert_eq!(*guard.travelled_distances.last().unwrap(), 4usize);
+
+
+    }
+
+    #[test]
+    fn test_part_1_direction_left_away_from_diagonal() {
+        // The guard walks left along its row from its own column. Starting from the column
+        // numbered like its row instead misses the obstruction and walks the guard off the map.
+        let (board, mut guard) = input_generator_day6(".........\n..#...<..");
+
+        let result = guard.move_to_next_turn(&board);
+
+        assert!(result.is_some());
+        let (current_direction, row, column) = guard.get_current_state().unwrap();
+        assert_eq!(*current_direction, Direction::Up);
+        assert_eq!((row, column), (1, 3));
+        assert_eq!(*guard.travelled_distances.last().unwrap(), 3usize);
+        assert_eq!(guard.visited_count(), 4);
+    }
+
+    #[test]
+    fn test_part_1_direction_right_obstruction() {
+        let input = fixtures::load(2024, "day6/day6_starting_right_obstruction.txt");
+
+        let (board, guard) = input_generator_day6(&input);
+        println!("{:?}", board);
+        let mut guard = guard.clone();
+        println!("Guard: {}", guard);
+
+        let result = guard.move_to_next_turn(&board);
+
+        assert!(result.is_some());
+
+        let (current_direction, row, column) = guard.get_current_state().unwrap();
+        assert!(matches!(*current_direction, Direction::Down));
+        assert_eq!(row, guard.starting_row);
+        assert_eq!(*guard.travelled_distances.last().unwrap(), 2usize);
+        assert_eq!(column, 2);
+
+    }
+
+    #[test]
+    fn test_part_1_direction_right_no_obstruction() {
+        let input = fixtures::load(2024, "day6/day6_starting_right_no_obstruction.txt");
+
+        let (board, guard) = input_generator_day6(&input);
+        println!("{:?}", board);
+        let mut guard = guard.clone();
+        println!("Guard: {}", guard);
+
+        let result = guard.move_to_next_turn(&board);
+
+        assert!(result.is_none());
+        assert_eq!(*guard.travelled_distances.last().unwrap(), 0usize);
+
+
+    }
+
+}
\ No newline at end of file
@@ -0,0 +1,242 @@
+//! Draws the guard's patrol over the lab map the way the puzzle description does: `|` and `-`
+//! for cells walked vertically or horizontally, `+` where the guard turned or crossed its own path
+//! and `O` for a candidate obstruction.
+//!
+//! Maps can be printed to a terminal with ANSI colours or, with the `render` feature, exported as
+//! PNG frames or an animated GIF of the walk.
+
+use std::fmt::Write;
+
+use ndarray::Array2;
+
+use super::{Guard, Step};
+use crate::grid::Grid;
+
+/// Position of an extra obstruction, drawn as `O`
+pub type Obstruction = Option<(usize, usize)>;
+
+/// The map with the first `steps` overlaid on it
+pub fn overlay(board: &Grid<char>, steps: &[Step], obstruction: Obstruction) -> Array2<char> {
+    let mut grid = board.cells().clone();
+    for step in steps {
+        let cell = &mut grid[(step.row, step.column)];
+        *cell = match (*cell, step.direction.is_vertical()) {
+            // The guard's starting position keeps its symbol
+            ('^' | 'v' | '<' | '>', _) => *cell,
+            ('-', true) | ('|', false) | ('+', _) => '+',
+            (_, true) => '|',
+            (_, false) => '-',
+        };
+    }
+    if let Some(position) = obstruction {
+        grid[position] = 'O';
+    }
+    grid
+}
+
+/// One map per turn of the guard's walk, starting before it has moved and ending with the whole
+/// walk
+pub fn frames(board: &Grid<char>, guard: &Guard, obstruction: Obstruction) -> Vec<Array2<char>> {
+    let steps = guard.steps();
+    turn_ends(&steps)
+        .into_iter()
+        .map(|end| overlay(board, &steps[..end], obstruction))
+        .collect()
+}
+
+/// Number of steps walked before the guard moves, after each turn and at the end of the walk
+pub fn turn_ends(steps: &[Step]) -> Vec<usize> {
+    let mut ends = vec![0];
+    for end in 1..=steps.len() {
+        // A turn ends with the guard facing its new direction on the cell it turned on
+        let turned = end > 1 && steps[end - 1].direction != steps[end - 2].direction;
+        if turned || end == steps.len() {
+            ends.push(end);
+        }
+    }
+    ends
+}
+
+/// The map as lines of text
+pub fn to_text(grid: &Array2<char>) -> String {
+    let mut text = String::with_capacity(grid.len() + grid.nrows());
+    for row in grid.rows() {
+        text.extend(row.iter());
+        text.push('\n');
+    }
+    text
+}
+
+/// The map as lines of text coloured with ANSI escape codes
+pub fn to_ansi(grid: &Array2<char>) -> String {
+    let mut text = String::new();
+    for row in grid.rows() {
+        let mut current = None;
+        for &cell in row {
+            let colour = ansi_colour(cell);
+            if current != Some(colour) {
+                write!(text, "\x1b[{}m", colour).unwrap();
+                current = Some(colour);
+            }
+            text.push(cell);
+        }
+        text.push_str("\x1b[0m\n");
+    }
+    text
+}
+
+/// SGR parameters for a cell
+fn ansi_colour(cell: char) -> &'static str {
+    match cell {
+        '#' => "1;37",
+        '|' | '-' => "33",
+        '+' => "1;33",
+        'O' => "1;31",
+        '^' | 'v' | '<' | '>' => "1;32",
+        _ => "2",
+    }
+}
+
+#[cfg(feature = "render")]
+pub use self::image::{write_gif, write_png};
+
+#[cfg(feature = "render")]
+mod image {
+    use std::fs::File;
+    use std::io::{self, BufWriter};
+    use std::path::Path;
+
+    use ndarray::Array2;
+
+    /// Colours of the indexed images, in the order of [`palette_index`]
+    const PALETTE: [[u8; 3]; 6] = [
+        [15, 15, 35],
+        [204, 204, 204],
+        [255, 255, 102],
+        [255, 153, 0],
+        [230, 40, 40],
+        [0, 204, 0],
+    ];
+
+    fn palette_index(cell: char) -> u8 {
+        match cell {
+            '#' => 1,
+            '|' | '-' => 2,
+            '+' => 3,
+            'O' => 4,
+            '^' | 'v' | '<' | '>' => 5,
+            _ => 0,
+        }
+    }
+
+    /// Palette indices of the map drawn with `scale` x `scale` pixels per cell
+    fn pixels(grid: &Array2<char>, scale: usize) -> (u16, u16, Vec<u8>) {
+        let (rows, columns) = grid.dim();
+        let (width, height) = (columns * scale, rows * scale);
+        let mut pixels = Vec::with_capacity(width * height);
+        for row in grid.rows() {
+            let line = row
+                .iter()
+                .flat_map(|&cell| std::iter::repeat_n(palette_index(cell), scale))
+                .collect::<Vec<u8>>();
+            for _ in 0..scale {
+                pixels.extend_from_slice(&line);
+            }
+        }
+        let dimension = |size: usize| u16::try_from(size).expect("Image is too large");
+        (dimension(width), dimension(height), pixels)
+    }
+
+    pub fn write_png(grid: &Array2<char>, scale: usize, path: &Path) -> io::Result<()> {
+        let (width, height, pixels) = pixels(grid, scale);
+        let mut encoder =
+            png::Encoder::new(BufWriter::new(File::create(path)?), width.into(), height.into());
+        encoder.set_color(png::ColorType::Indexed);
+        encoder.set_depth(png::BitDepth::Eight);
+        encoder.set_palette(PALETTE.concat());
+        let mut writer = encoder.write_header().map_err(io::Error::other)?;
+        writer.write_image_data(&pixels).map_err(io::Error::other)
+    }
+
+    /// Writes the frames as an endlessly repeating GIF, showing each for `delay` hundredths of a
+    /// second
+    pub fn write_gif(frames: &[Array2<char>], scale: usize, delay: u16, path: &Path) -> io::Result<()> {
+        let Some(first) = frames.first() else {
+            return Err(io::Error::new(io::ErrorKind::InvalidInput, "No frames to write"));
+        };
+        let (width, height, _) = pixels(first, scale);
+
+        let file = BufWriter::new(File::create(path)?);
+        let mut encoder =
+            gif::Encoder::new(file, width, height, &PALETTE.concat()).map_err(io::Error::other)?;
+        encoder.set_repeat(gif::Repeat::Infinite).map_err(io::Error::other)?;
+        for grid in frames {
+            let (width, height, pixels) = pixels(grid, scale);
+            let mut frame = gif::Frame::from_indexed_pixels(width, height, pixels, None);
+            frame.delay = delay;
+            encoder.write_frame(&frame).map_err(io::Error::other)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures;
+    use crate::y2024::day6::{input_generator_day6, Direction};
+
+    #[test]
+    fn test_overlay_matches_puzzle() {
+        let (board, mut guard) = input_generator_day6(&fixtures::load(2024, "day6/day6.txt"));
+        guard.patrol(&board);
+
+        // The first four legs of the walk, ending back on the starting row
+        let expected = "....#.....
+....+---+#
+....|...|.
+..#.|...|.
+....|..#|.
+....|...|.
+.#..^---+.
+........#.
+#.........
+......#...
+";
+        let text = to_text(&overlay(&board, &guard.steps()[..22], None));
+        assert_eq!(text, expected);
+    }
+
+    #[test]
+    fn test_overlay_obstruction_and_crossing() {
+        let board = Grid::parse(".^.\n...\n#..");
+        let steps = [(1, 1, true), (1, 0, false), (1, 1, false), (1, 2, false)].map(
+            |(row, column, vertical)| Step {
+                row,
+                column,
+                direction: if vertical { Direction::Up } else { Direction::Right },
+            },
+        );
+
+        let grid = overlay(&board, &steps, Some((2, 2)));
+        assert_eq!(to_text(&grid), ".^.\n-+-\n#.O\n");
+    }
+
+    #[test]
+    fn test_frames() {
+        let (board, mut guard) = input_generator_day6(&fixtures::load(2024, "day6/day6.txt"));
+        guard.patrol(&board);
+
+        let frames = frames(&board, &guard, None);
+        // Before moving, after each of the eight turns and after leaving the map
+        assert_eq!(frames.len(), guard.turns.len() + 2);
+        assert_eq!(frames[0], *board.cells());
+        assert_eq!(frames[1][(1, 4)], '+');
+    }
+
+    #[test]
+    fn test_ansi() {
+        let board = Array2::from_shape_vec((1, 3), "#.O".chars().collect()).unwrap();
+        assert_eq!(to_ansi(&board), "\x1b[1;37m#\x1b[2m.\x1b[1;31mO\x1b[0m\n");
+    }
+}
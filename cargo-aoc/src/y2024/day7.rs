@@ -0,0 +1,181 @@
+use aoc_generators::processors::line_processor_trait::LineProcessor;
+use aoc_generators::processors::regex_line_processor::{
+    RegexLineProcessor, RegexLineProcessorMode,
+};
+use tracing::debug;
+use crate::answer::Answer;
+use crate::solver::Solver;
+
+/// A calibration line: the test value and the numbers that should combine into it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Equation {
+    pub target: u64,
+    pub operands: Vec<u64>,
+}
+
+pub struct Day7LineProcessor {
+    regex_line_processor: RegexLineProcessor,
+}
+
+impl Day7LineProcessor {
+    pub fn new() -> Day7LineProcessor {
+        // Splitting on the colon as well leaves the test value as the first token
+        let regex_line_processor =
+            RegexLineProcessor::new(r":?\s+", RegexLineProcessorMode::Split(false));
+
+        Self {
+            regex_line_processor,
+        }
+    }
+}
+
+impl Default for Day7LineProcessor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl LineProcessor for Day7LineProcessor {
+    type Item = Equation;
+    type ProcessorError = String;
+
+    fn process(&self, line: &str) -> Result<Self::Item, Self::ProcessorError> {
+        if !line.contains(':') {
+            return Err(format!("Missing ':' after the test value in {:?}", line));
+        }
+        let tokens = self.regex_line_processor.process(line)?;
+        let numbers = tokens
+            .iter()
+            .map(|token| token.parse::<u64>().map_err(|e| format!("Failed to parse {:?}: {}", token, e)))
+            .collect::<Result<Vec<u64>, String>>()?;
+
+        match numbers.split_first() {
+            Some((&target, operands)) if !operands.is_empty() => Ok(Equation {
+                target,
+                operands: operands.to_vec(),
+            }),
+            _ => Err(format!("Expected a test value and at least one number in {:?}", line)),
+        }
+    }
+}
+
+#[aoc_generator(day7)]
+pub fn input_generator(input: &str) -> Vec<Equation> {
+    aoc_generators::apply_processor_to_input(input, &Day7LineProcessor::new(), true)
+        .expect("Failed to process input")
+}
+
+/// Whether some choice of operators, evaluated left to right, turns the operands into the target.
+///
+/// Works backwards from the last operand, undoing each operator that could have produced the
+/// target: subtraction always applies, division only when it is exact and stripping a suffix only
+/// when the target ends in the operand's digits. Most branches die after a step or two, instead of
+/// trying every one of the 2^n or 3^n operator combinations.
+fn is_solvable(target: u64, operands: &[u64], concatenation: bool) -> bool {
+    let Some((&last, rest)) = operands.split_last() else {
+        return false;
+    };
+    if rest.is_empty() {
+        return target == last;
+    }
+
+    if target >= last && is_solvable(target - last, rest, concatenation) {
+        return true;
+    }
+    match last {
+        // Multiplying by zero gives zero whatever came before
+        0 if target == 0 => return true,
+        0 => (),
+        _ if target.is_multiple_of(last) && is_solvable(target / last, rest, concatenation) => return true,
+        _ => (),
+    }
+    if concatenation {
+        let suffix = 10u64.pow(last.checked_ilog10().unwrap_or(0) + 1);
+        if target % suffix == last && is_solvable(target / suffix, rest, concatenation) {
+            return true;
+        }
+    }
+    false
+}
+
+fn calibration_result(input: &[Equation], concatenation: bool) -> u64 {
+    input
+        .iter()
+        .filter(|equation| is_solvable(equation.target, &equation.operands, concatenation))
+        .inspect(|equation| debug!("Solvable: {:?}", equation))
+        .map(|equation| equation.target)
+        .sum()
+}
+
+#[aoc(day7, part1)]
+pub fn part_1(input: &[Equation]) -> Answer {
+    calibration_result(input, false).into()
+}
+
+#[aoc(day7, part2)]
+pub fn part_2(input: &[Equation]) -> Answer {
+    calibration_result(input, true).into()
+}
+
+pub struct Day7;
+
+impl Solver for Day7 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 7;
+    type Input = Vec<Equation>;
+    type Output = Answer;
+
+    fn parse(input: &str) -> Self::Input {
+        input_generator(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Output {
+        part_1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Option<Self::Output> {
+        Some(part_2(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures;
+
+    #[test]
+    fn test_day_7_line_processor() {
+        let processor = Day7LineProcessor::new();
+
+        let equation = processor.process("3267: 81 40 27").unwrap();
+        assert_eq!(equation, Equation { target: 3267, operands: vec![81, 40, 27] });
+
+        assert!(processor.process("3267 81 40 27").is_err());
+        assert!(processor.process("3267:").is_err());
+        assert!(processor.process("3267: 81 x").is_err());
+    }
+
+    #[test]
+    fn test_is_solvable() {
+        assert!(is_solvable(190, &[10, 19], false));
+        assert!(is_solvable(3267, &[81, 40, 27], false));
+        assert!(!is_solvable(156, &[15, 6], false));
+        assert!(is_solvable(156, &[15, 6], true));
+        assert!(is_solvable(7290, &[6, 8, 6, 15], true));
+        assert!(!is_solvable(21037, &[9, 7, 18, 13], true));
+        assert!(is_solvable(0, &[5, 0], false));
+        assert!(is_solvable(50, &[5, 0], true));
+    }
+
+    #[test]
+    fn test_part_1() {
+        let input = input_generator(&fixtures::load(2024, "day7.txt"));
+        assert_eq!(part_1(&input), 3749);
+    }
+
+    #[test]
+    fn test_part_2() {
+        let input = input_generator(&fixtures::load(2024, "day7.txt"));
+        assert_eq!(part_2(&input), 11387);
+    }
+}
@@ -0,0 +1,162 @@
+use std::collections::{BTreeMap, HashSet};
+use aoc_generators::processors::board_generator::generate_2d_board_char;
+use ndarray::Array2;
+use tracing::debug;
+use crate::answer::Answer;
+use crate::solver::Solver;
+
+type Position = (isize, isize);
+
+/// The antennas of the map grouped by frequency, along with the map's size
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Antennas {
+    pub rows: isize,
+    pub columns: isize,
+    pub frequencies: BTreeMap<char, Vec<Position>>,
+}
+
+impl Antennas {
+    fn contains(&self, (row, column): Position) -> bool {
+        (0..self.rows).contains(&row) && (0..self.columns).contains(&column)
+    }
+
+    /// Positions from `start` onwards in steps of `step`, up to the edge of the map
+    fn line(&self, start: Position, step: Position) -> impl Iterator<Item = Position> + '_ {
+        (0..)
+            .map(move |i| (start.0 + i * step.0, start.1 + i * step.1))
+            .take_while(|&position| self.contains(position))
+    }
+
+    /// Number of distinct positions that are antinodes of any pair of same frequency antennas.
+    /// `antinodes` gives those of a single pair, from the pair's positions and the step between
+    /// them.
+    fn count_antinodes<I>(&self, antinodes: impl Fn(Position, Position, Position) -> I) -> usize
+    where
+        I: IntoIterator<Item = Position>,
+    {
+        let mut found = HashSet::new();
+        for (frequency, positions) in &self.frequencies {
+            for (i, &a) in positions.iter().enumerate() {
+                for &b in &positions[i + 1..] {
+                    let step = (b.0 - a.0, b.1 - a.1);
+                    found.extend(antinodes(a, b, step).into_iter().filter(|&p| self.contains(p)));
+                }
+            }
+            debug!("Antinodes after frequency {}: {}", frequency, found.len());
+        }
+        found.len()
+    }
+}
+
+#[aoc_generator(day8)]
+pub fn input_generator(input: &str) -> Antennas {
+    antennas(&generate_2d_board_char(input))
+}
+
+fn antennas(board: &Array2<char>) -> Antennas {
+    let mut frequencies: BTreeMap<char, Vec<Position>> = BTreeMap::new();
+    for ((row, column), &cell) in board.indexed_iter() {
+        if cell != '.' {
+            frequencies.entry(cell).or_default().push((row as isize, column as isize));
+        }
+    }
+    let (rows, columns) = board.dim();
+    Antennas {
+        rows: rows as isize,
+        columns: columns as isize,
+        frequencies,
+    }
+}
+
+fn gcd(a: isize, b: isize) -> isize {
+    if b == 0 { a.abs() } else { gcd(b, a % b) }
+}
+
+#[aoc(day8, part1)]
+pub fn part_1(input: &Antennas) -> Answer {
+    // The single antinode beyond each antenna, twice as far from one as from the other
+    input
+        .count_antinodes(|a, b, step| [(b.0 + step.0, b.1 + step.1), (a.0 - step.0, a.1 - step.1)])
+        .into()
+}
+
+#[aoc(day8, part2)]
+pub fn part_2(input: &Antennas) -> Answer {
+    // Every grid point in line with the pair, including the antennas themselves. Reducing the step
+    // by the gcd finds the points between whole multiples of the antennas' distance.
+    input
+        .count_antinodes(|a, _, step| {
+            let divisor = gcd(step.0, step.1);
+            let step = (step.0 / divisor, step.1 / divisor);
+            let behind = (a.0 - step.0, a.1 - step.1);
+            input.line(a, step).chain(input.line(behind, (-step.0, -step.1))).collect::<Vec<_>>()
+        })
+        .into()
+}
+
+pub struct Day8;
+
+impl Solver for Day8 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 8;
+    type Input = Antennas;
+    type Output = Answer;
+
+    fn parse(input: &str) -> Self::Input {
+        input_generator(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Output {
+        part_1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Option<Self::Output> {
+        Some(part_2(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures;
+
+    #[test]
+    fn test_input_generator() {
+        let antennas = input_generator("..a.\n.A..\n...a");
+        assert_eq!((antennas.rows, antennas.columns), (3, 4));
+        assert_eq!(antennas.frequencies[&'a'], vec![(0, 2), (2, 3)]);
+        assert_eq!(antennas.frequencies[&'A'], vec![(1, 1)]);
+    }
+
+    #[test]
+    fn test_gcd() {
+        assert_eq!(gcd(4, 6), 2);
+        assert_eq!(gcd(-4, 6), 2);
+        assert_eq!(gcd(0, -3), 3);
+        assert_eq!(gcd(7, 0), 7);
+    }
+
+    #[test]
+    fn test_part_1() {
+        let input = input_generator(&fixtures::load(2024, "day8.txt"));
+        assert_eq!(part_1(&input), 14);
+    }
+
+    #[test]
+    fn test_part_2() {
+        let input = input_generator(&fixtures::load(2024, "day8.txt"));
+        assert_eq!(part_2(&input), 34);
+
+        // The example from the description where only the three T antennas count
+        let input = input_generator("T.........\n...T......\n.T........\n..........\n..........\n..........\n..........\n..........\n..........\n..........");
+        assert_eq!(part_2(&input), 9);
+    }
+
+    #[test]
+    fn test_part_2_reduces_steps() {
+        // Two rows and four columns apart, so the cell halfway between them is in line as well
+        let input = input_generator(".......\n.a.....\n.......\n.....a.\n.......");
+        assert_eq!(part_1(&input), 0);
+        assert_eq!(part_2(&input), 3);
+    }
+}
@@ -0,0 +1,198 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use tracing::debug;
+use crate::answer::Answer;
+use crate::solver::Solver;
+
+/// A run of blocks on the disk
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Span {
+    pub start: usize,
+    pub length: usize,
+}
+
+/// The disk map, with files indexed by their id
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiskMap {
+    pub files: Vec<Span>,
+    pub free: Vec<Span>,
+}
+
+#[aoc_generator(day9)]
+pub fn input_generator(input: &str) -> DiskMap {
+    let mut disk = DiskMap {
+        files: Vec::new(),
+        free: Vec::new(),
+    };
+    let mut start = 0;
+    for (i, byte) in input.trim_end().bytes().enumerate() {
+        if !byte.is_ascii_digit() {
+            panic!("Unexpected {:?} in disk map", byte as char);
+        }
+        let span = Span {
+            start,
+            length: (byte - b'0') as usize,
+        };
+        start += span.length;
+        // The map alternates between files and free space, starting with a file
+        if i % 2 == 0 {
+            disk.files.push(span);
+        } else if span.length > 0 {
+            disk.free.push(span);
+        }
+    }
+    disk
+}
+
+/// Checksum contribution of a file with the given id occupying `length` blocks from `start`
+fn span_checksum(id: usize, start: usize, length: usize) -> u64 {
+    let positions = length * start + length * length.saturating_sub(1) / 2;
+    (id * positions) as u64
+}
+
+#[aoc(day9, part1)]
+pub fn part_1(disk: &DiskMap) -> Answer {
+    // Blocks still in each file's original place, always a prefix of it as blocks are taken from
+    // the end
+    let mut remaining = disk.files.iter().map(|file| file.length).collect::<Vec<usize>>();
+    // Files from this id onwards have had every block moved. Files can be empty, including the
+    // first, so this can reach zero.
+    let mut in_place = disk.files.len();
+    let mut checksum = 0;
+
+    'free: for free in &disk.free {
+        let (mut position, mut space) = (free.start, free.length);
+        while space > 0 {
+            let Some(last) = in_place.checked_sub(1) else {
+                break 'free;
+            };
+            if disk.files[last].start < position {
+                break 'free;
+            }
+            let moved = space.min(remaining[last]);
+            checksum += span_checksum(last, position, moved);
+            position += moved;
+            space -= moved;
+            remaining[last] -= moved;
+            if remaining[last] == 0 {
+                in_place = last;
+            }
+        }
+    }
+    debug!("Files from {} onwards moved", in_place);
+
+    checksum += (0..in_place)
+        .map(|id| span_checksum(id, disk.files[id].start, remaining[id]))
+        .sum::<u64>();
+    checksum.into()
+}
+
+#[aoc(day9, part2)]
+pub fn part_2(disk: &DiskMap) -> Answer {
+    // Starts of the free spans of each length, leftmost first. Space freed by moving a file is
+    // never reused, as every file still to move lies to its left.
+    let mut free_by_length = vec![BinaryHeap::new(); 10];
+    for free in &disk.free {
+        free_by_length[free.length].push(Reverse(free.start));
+    }
+
+    let mut checksum = 0;
+    for (id, file) in disk.files.iter().enumerate().rev() {
+        // The leftmost span the file fits in, which may be in any of the longer heaps
+        let target = (file.length..free_by_length.len())
+            .filter_map(|length| free_by_length[length].peek().map(|&Reverse(start)| (start, length)))
+            .min()
+            .filter(|&(start, _)| start < file.start);
+
+        let start = match target {
+            Some((start, length)) => {
+                free_by_length[length].pop();
+                if length > file.length {
+                    free_by_length[length - file.length].push(Reverse(start + file.length));
+                }
+                debug!("File {} moves from {} to {}", id, file.start, start);
+                start
+            }
+            None => file.start,
+        };
+        checksum += span_checksum(id, start, file.length);
+    }
+    checksum.into()
+}
+
+pub struct Day9;
+
+impl Solver for Day9 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 9;
+    type Input = DiskMap;
+    type Output = Answer;
+
+    fn parse(input: &str) -> Self::Input {
+        input_generator(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Output {
+        part_1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Option<Self::Output> {
+        Some(part_2(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures;
+
+    #[test]
+    fn test_input_generator() {
+        let disk = input_generator("12345\n");
+        assert_eq!(
+            disk.files,
+            vec![
+                Span { start: 0, length: 1 },
+                Span { start: 3, length: 3 },
+                Span { start: 10, length: 5 }
+            ]
+        );
+        assert_eq!(disk.free, vec![Span { start: 1, length: 2 }, Span { start: 6, length: 4 }]);
+    }
+
+    #[test]
+    fn test_span_checksum() {
+        // File 2 in blocks 4, 5 and 6
+        assert_eq!(span_checksum(2, 4, 3), 30);
+        assert_eq!(span_checksum(2, 4, 0), 0);
+    }
+
+    #[test]
+    fn test_part_1() {
+        let input = input_generator(&fixtures::load(2024, "day9.txt"));
+        assert_eq!(part_1(&input), 1928);
+
+        // 0..111....22222 compacts to 022111222
+        assert_eq!(part_1(&input_generator("12345")), 60);
+    }
+
+    #[test]
+    fn test_part_2() {
+        let input = input_generator(&fixtures::load(2024, "day9.txt"));
+        assert_eq!(part_2(&input), 2858);
+    }
+
+    #[test]
+    fn test_empty_files() {
+        assert_eq!(part_1(&input_generator("")), 0);
+        assert_eq!(part_2(&input_generator("")), 0);
+
+        // An empty file 0 with free space at the very start, which file 1 moves into
+        assert_eq!(part_1(&input_generator("021")), 0);
+        assert_eq!(part_2(&input_generator("021")), 0);
+
+        // File 1 only partly fits into the space before it
+        assert_eq!(part_1(&input_generator("012")), 1);
+        assert_eq!(part_2(&input_generator("012")), 3);
+    }
+}
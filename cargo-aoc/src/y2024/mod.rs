@@ -0,0 +1,42 @@
+//! Advent of Code 2024
+
+pub mod day1;
+pub mod day2;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+pub mod day8;
+pub mod day9;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+
+pub mod synth;
+
+use crate::solver::Registry;
+
+/// Adds every implemented day of 2024 to `registry`
+pub fn register(registry: &mut Registry) {
+    registry.register(day1::Day1);
+    registry.register(day2::Day2);
+    registry.register(day3::Day3);
+    registry.register(day4::Day4);
+    registry.register(day5::Day5);
+    registry.register(day6::Day6);
+    registry.register(day7::Day7);
+    registry.register(day8::Day8);
+    registry.register(day9::Day9);
+    registry.register(day10::Day10);
+    registry.register(day11::Day11);
+    registry.register(day12::Day12);
+    registry.register(day13::Day13);
+    registry.register(day14::Day14);
+    registry.register(day15::Day15);
+    registry.register(day16::Day16);
+}
@@ -0,0 +1,294 @@
+//! Synthetic inputs for the 2024 puzzles, built on the shared generators in [`crate::synth`].
+
+use std::collections::HashSet;
+
+use crate::synth::{grid_side, SplitMix64};
+
+/// Days that have a synthetic input generator
+pub const DAYS: [u8; 6] = [1, 2, 3, 4, 5, 6];
+
+/// Generates an input for `day` whose size is roughly that of a real puzzle input multiplied by
+/// `scale`. Guard maps are always loop free so that they can be patrolled.
+pub fn generate(day: u8, seed: u64, scale: usize) -> Option<String> {
+    let scale = scale.max(1);
+    match day {
+        1 => Some(day1(seed, 1000 * scale)),
+        2 => Some(day2(seed, 1000 * scale)),
+        3 => Some(day3(seed, 18000 * scale)),
+        4 => Some(day4(seed, grid_side(140, scale))),
+        5 => Some(day5(seed, 49, 200 * scale)),
+        6 => Some(day6(seed, grid_side(130, scale), true)),
+        _ => None,
+    }
+}
+
+/// Day 1: `lines` pairs of five digit location ids. Roughly a third of the right hand list is
+/// drawn from the left hand list so the similarity score is not trivially zero.
+pub fn day1(seed: u64, lines: usize) -> String {
+    let mut rng = SplitMix64::new(seed);
+    let left = (0..lines)
+        .map(|_| rng.between(10000, 99999))
+        .collect::<Vec<i64>>();
+
+    left.iter()
+        .map(|l| {
+            let r = if !left.is_empty() && rng.chance(1, 3) {
+                *rng.pick(&left)
+            } else {
+                rng.between(10000, 99999)
+            };
+            format!("{}   {}", l, r)
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+/// Day 2: `reports` reports of 5 to 8 levels. Reports start out safe and then a share of them
+/// gets one or two levels corrupted, so both parts have safe and unsafe reports to find.
+pub fn day2(seed: u64, reports: usize) -> String {
+    let mut rng = SplitMix64::new(seed);
+    (0..reports)
+        .map(|_| {
+            let length = rng.between(5, 8) as usize;
+            let step_sign = if rng.chance(1, 2) { 1 } else { -1 };
+            // High enough that a decreasing report stays positive even if every step is the largest
+            let mut level = rng.between(20, 70).max(3 * (length as i64 - 1) + 1);
+            let mut report = Vec::with_capacity(length);
+            for _ in 0..length {
+                report.push(level);
+                level += step_sign * rng.between(1, 3);
+            }
+            let corruptions = rng.below(3);
+            for _ in 0..corruptions {
+                let index = rng.below(length);
+                report[index] = (report[index] + rng.between(-5, 5)).max(1);
+            }
+            report
+                .iter()
+                .map(|l| l.to_string())
+                .collect::<Vec<String>>()
+                .join(" ")
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+/// Day 3: about `length` bytes of corrupted memory, spread over lines, mixing valid `mul(a,b)`,
+/// `do()` and `don't()` instructions with near misses and noise
+pub fn day3(seed: u64, length: usize) -> String {
+    const NOISE: &[u8] = b"!@#$%^&*()[]{}<>?/~'+-,;: ";
+    const DISTRACTORS: &[&str] = &[
+        "mul[3,7]", "mul(32,64]", "mul ( 2 , 4 )", "mul(4*", "mul(6,9!", "?(12,34)", "what()",
+        "select()", "from()", "how()", "who()", "where()", "when()", "why()", "mul(1234,5)",
+        "don't", "do(", "xmul", "mul(,)",
+    ];
+
+    let mut rng = SplitMix64::new(seed);
+    let mut memory = String::with_capacity(length + 16);
+    let mut line_length = 0;
+    while memory.len() < length {
+        let before = memory.len();
+        match rng.below(10) {
+            0..=2 => memory.push_str(&format!("mul({},{})", rng.between(1, 999), rng.between(1, 999))),
+            3 => memory.push_str(if rng.chance(1, 2) { "do()" } else { "don't()" }),
+            4..=5 => memory.push_str(rng.pick::<&str>(DISTRACTORS)),
+            _ => {
+                for _ in 0..rng.between(1, 4) {
+                    memory.push(*rng.pick(NOISE) as char);
+                }
+            }
+        }
+        line_length += memory.len() - before;
+        if line_length > 3000 {
+            memory.push('\n');
+            line_length = 0;
+        }
+    }
+    memory
+}
+
+/// Day 4: a `size` x `size` grid made only of the letters `X`, `M`, `A` and `S`
+pub fn day4(seed: u64, size: usize) -> String {
+    const LETTERS: &[char] = &['X', 'M', 'A', 'S'];
+
+    let mut rng = SplitMix64::new(seed);
+    (0..size)
+        .map(|_| (0..size).map(|_| *rng.pick(LETTERS)).collect::<String>())
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+/// Day 5: ordering rules between `pages` pages followed by `updates` updates of an odd number of
+/// pages. The rules are derived from a single hidden total order, so the rules restricted to any
+/// update are acyclic and every update can be sorted. About half of the updates are already in
+/// order.
+pub fn day5(seed: u64, pages: usize, updates: usize) -> String {
+    assert!(pages >= 5, "Need at least 5 pages to build updates");
+
+    let mut rng = SplitMix64::new(seed);
+    let mut order = (10..10 + pages as u32).collect::<Vec<u32>>();
+    rng.shuffle(&mut order);
+
+    let mut rules = Vec::with_capacity(pages * (pages - 1) / 2);
+    for i in 0..order.len() {
+        for j in i + 1..order.len() {
+            rules.push(format!("{}|{}", order[i], order[j]));
+        }
+    }
+    rng.shuffle(&mut rules);
+
+    let max_length = if pages.is_multiple_of(2) { pages - 1 } else { pages }.min(23);
+    let update_lines = (0..updates).map(|_| {
+        let length = 2 * rng.between(2, (max_length / 2) as i64) as usize + 1;
+        let mut positions = (0..order.len()).collect::<Vec<usize>>();
+        rng.shuffle(&mut positions);
+        positions.truncate(length);
+        if rng.chance(1, 2) {
+            positions.sort();
+        }
+        positions
+            .iter()
+            .map(|&p| order[p].to_string())
+            .collect::<Vec<String>>()
+            .join(",")
+    });
+
+    format!("{}\n\n{}", rules.join("\n"), update_lines.collect::<Vec<String>>().join("\n"))
+}
+
+/// Day 6: a `size` x `size` map with scattered obstructions and a guard facing up. When
+/// `loop_free` is set, maps on which the guard would walk in a loop are rejected and regenerated,
+/// so the guard is guaranteed to leave the map.
+pub fn day6(seed: u64, size: usize, loop_free: bool) -> String {
+    assert!(size > 0, "Map must have at least one cell");
+
+    let mut rng = SplitMix64::new(seed);
+    loop {
+        let mut map = vec![vec!['.'; size]; size];
+        for row in map.iter_mut() {
+            for cell in row.iter_mut() {
+                if rng.chance(1, 20) {
+                    *cell = '#';
+                }
+            }
+        }
+        let (row, column) = (rng.below(size), rng.below(size));
+        map[row][column] = '^';
+
+        if !loop_free || guard_leaves_map(&map, row, column) {
+            return map
+                .iter()
+                .map(|row| row.iter().collect::<String>())
+                .collect::<Vec<String>>()
+                .join("\n");
+        }
+    }
+}
+
+/// Walks the guard using the puzzle rules: step forward unless blocked, otherwise turn right
+fn guard_leaves_map(map: &[Vec<char>], mut row: usize, mut column: usize) -> bool {
+    const STEPS: [(isize, isize); 4] = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+
+    let mut heading = 0;
+    let mut seen = HashSet::new();
+    while seen.insert((row, column, heading)) {
+        let (dr, dc) = STEPS[heading];
+        let (Some(next_row), Some(next_column)) =
+            (row.checked_add_signed(dr), column.checked_add_signed(dc))
+        else {
+            return true;
+        };
+        if next_row >= map.len() || next_column >= map[next_row].len() {
+            return true;
+        }
+        if map[next_row][next_column] == '#' {
+            heading = (heading + 1) % 4;
+        } else {
+            row = next_row;
+            column = next_column;
+        }
+    }
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::y2024::{day1, day2, day5};
+
+    #[test]
+    fn test_generators_are_deterministic() {
+        for day in DAYS {
+            assert_eq!(generate(day, 7, 1), generate(day, 7, 1));
+            assert_ne!(generate(day, 7, 1), generate(day, 8, 1));
+        }
+        assert_eq!(generate(7, 7, 1), None);
+    }
+
+    #[test]
+    fn test_day1_parses() {
+        let input = day1(1, 500);
+        let parsed = day1::input_generator(&input);
+        assert_eq!(parsed.len(), 500);
+    }
+
+    #[test]
+    fn test_day2_parses() {
+        let input = day2(2, 300);
+        let parsed = day2::input_generator(&input);
+        assert_eq!(parsed.len(), 300);
+        assert!(parsed.iter().all(|report| (5..=8).contains(&report.len())));
+        assert!(parsed.iter().flatten().all(|&level| level > 0));
+
+        // A seed whose reports used to include one falling from its start to zero
+        let parsed = day2::input_generator(&day2(45, 50));
+        assert!(parsed.iter().flatten().all(|&level| level > 0));
+    }
+
+    #[test]
+    fn test_day4_is_square() {
+        let input = day4(4, 37);
+        assert_eq!(input.lines().count(), 37);
+        assert!(input.lines().all(|line| line.len() == 37));
+    }
+
+    #[test]
+    fn test_day5_updates_are_acyclic() {
+        let input = day5(5, 30, 100);
+        let (rules, updates) = day5::input_generator_day5(&input);
+
+        for update in &updates {
+            assert_eq!(update.len() % 2, 1);
+            // Every pair in the update is covered by a rule, so counting the pages each page must
+            // precede gives its position in the one valid order, and positions must be distinct
+            let mut ranks = update
+                .iter()
+                .map(|page| {
+                    update
+                        .iter()
+                        .filter(|other| rules.get(page).is_some_and(|after| after.contains(other)))
+                        .count()
+                })
+                .collect::<Vec<usize>>();
+            ranks.sort();
+            assert_eq!(ranks, (0..update.len()).collect::<Vec<usize>>());
+        }
+    }
+
+    #[test]
+    fn test_day6_loop_free() {
+        for seed in 0..20 {
+            let input = day6(seed, 40, true);
+            let map = input
+                .lines()
+                .map(|line| line.chars().collect())
+                .collect::<Vec<Vec<char>>>();
+            let (row, column) = map
+                .iter()
+                .enumerate()
+                .find_map(|(r, line)| line.iter().position(|&c| c == '^').map(|c| (r, c)))
+                .unwrap();
+            assert!(guard_leaves_map(&map, row, column));
+        }
+    }
+}